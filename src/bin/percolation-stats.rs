@@ -0,0 +1,146 @@
+extern crate algorithms;
+extern crate rand;
+
+use std::env;
+use std::process;
+use std::str::FromStr;
+
+use algorithms::percolation::factory::Implementation;
+use algorithms::percolation::stats::PercolationStats;
+use algorithms::percolation::stats_config::{StatsConfig, Lattice, Boundary};
+
+const USAGE: &'static str = "\
+usage: percolation-stats [options]
+
+options:
+    --size N              side size of the grid (default 200)
+    --trials T            number of Monte Carlo trials (default 100)
+    --threads N           number of threads running the trials (default 1)
+    --implementation I    brute-force, union-find or hack-union-find (default union-find),
+                          only for the site lattice with top-bottom boundary
+    --seed S              seed of the random sites (default random)
+    --lattice L           site, bond, cubic or directed (default site), bond needs size of at least 2
+    --boundary B          top-bottom, left-right or boundary-center (default top-bottom)
+    --format F            text, json or csv (default text)
+    --help                print this message";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Format {
+    Text,
+    Json,
+    Csv
+}
+
+// None asks for the usage message.
+fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Option<(StatsConfig, Format)>, String> {
+    let mut config = StatsConfig {
+        size: 200,
+        trials: 100,
+        threads: 1,
+        implementation: Implementation::UnionFind,
+        seed: rand::random(),
+        lattice: Lattice::Site,
+        boundary: Boundary::TopBottom
+    };
+    let mut format = Format::Text;
+    let mut implementation_given = false;
+    while let Some(arg) = args.next() {
+        if arg == "--help" || arg == "-h" {
+            return Ok(None);
+        }
+        let value = match args.next() {
+            Some(value) => value,
+            None => return Err(format!("missing value for {}", arg)),
+        };
+        match arg.as_str() {
+            "--size" => config.size = parse_number(&arg, &value)?,
+            "--trials" => config.trials = parse_number(&arg, &value)?,
+            "--threads" => config.threads = parse_number(&arg, &value)?,
+            "--seed" => config.seed = parse_number(&arg, &value)?,
+            "--implementation" => {
+                config.implementation = value.parse().map_err(|e| format!("{}", e))?;
+                implementation_given = true;
+            },
+            "--lattice" => config.lattice = match value.as_str() {
+                "site" => Lattice::Site,
+                "bond" => Lattice::Bond,
+                "cubic" => Lattice::Cubic,
+                "directed" => Lattice::Directed,
+                _ => return Err(format!("unknown lattice '{}'", value)),
+            },
+            "--boundary" => config.boundary = match value.as_str() {
+                "top-bottom" => Boundary::TopBottom,
+                "left-right" => Boundary::LeftRight,
+                "boundary-center" => Boundary::BoundaryCenter,
+                _ => return Err(format!("unknown boundary '{}'", value)),
+            },
+            "--format" => format = match value.as_str() {
+                "text" => Format::Text,
+                "json" => Format::Json,
+                "csv" => Format::Csv,
+                _ => return Err(format!("unknown format '{}'", value)),
+            },
+            _ => return Err(format!("unknown option {}", arg)),
+        }
+    }
+    config.check()?;
+    if implementation_given && !config.uses_implementation() {
+        return Err("implementation can only be chosen for the site lattice with top-bottom boundary".to_owned());
+    }
+    Ok(Some((config, format)))
+}
+
+fn parse_number<N: FromStr>(arg: &str, value: &str) -> Result<N, String> {
+    value.parse().map_err(|_| format!("invalid value '{}' for {}", value, arg))
+}
+
+// JSON has no literal for NaN or infinity.
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        format!("{}", value)
+    }
+    else {
+        "null".to_owned()
+    }
+}
+
+fn print(config: &StatsConfig, format: Format, stats: &PercolationStats) {
+    let implementation = if config.uses_implementation() { Some(format!("{}", config.implementation)) } else { None };
+    match format {
+        Format::Text => {
+            println!("mean                    = {}", stats.mean());
+            println!("stddev                  = {}", stats.stddev());
+            println!("95% confidence interval = [{}, {}]", stats.confidence_lo(), stats.confidence_hi());
+        },
+        Format::Json => {
+            let implementation = implementation.map(|name| format!("\"implementation\":\"{}\",", name)).unwrap_or_default();
+            println!("{{\"size\":{},\"trials\":{},{}\"seed\":{},\"lattice\":\"{}\",\"boundary\":\"{}\",\
+                \"mean\":{},\"stddev\":{},\"confidence_lo\":{},\"confidence_hi\":{}}}",
+                config.size, config.trials, implementation, config.seed, config.lattice_name(),
+                config.boundary_name(), json_number(stats.mean()), json_number(stats.stddev()),
+                json_number(stats.confidence_lo()), json_number(stats.confidence_hi()));
+        },
+        Format::Csv => {
+            println!("size,trials,implementation,seed,lattice,boundary,mean,stddev,confidence_lo,confidence_hi");
+            println!("{},{},{},{},{},{},{},{},{},{}",
+                config.size, config.trials, implementation.unwrap_or_default(), config.seed, config.lattice_name(),
+                config.boundary_name(), stats.mean(), stats.stddev(), stats.confidence_lo(), stats.confidence_hi());
+        },
+    }
+}
+
+fn main() {
+    let (config, format) = match parse(env::args().skip(1)) {
+        Ok(Some(parsed)) => parsed,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return;
+        },
+        Err(message) => {
+            eprintln!("percolation-stats: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        },
+    };
+    let stats = config.run();
+    print(&config, format, &stats);
+}
@@ -0,0 +1,218 @@
+extern crate algorithms;
+extern crate rand;
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader, Write};
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use algorithms::percolation::Percolation;
+use algorithms::percolation::factory::Implementation;
+use algorithms::percolation::render::to_ansi;
+use algorithms::percolation::replay::read_sites;
+use algorithms::percolation::stats::{random_order, trial_rng};
+
+const USAGE: &'static str = "\
+usage: percolation-viz [options]
+
+opens sites one batch at a time and redraws the grid with ansi colors,
+blocked sites are black, open sites white and full sites blue, the
+percolating path is highlighted once the system percolates
+
+options:
+    --size N              side size of the grid with random sites (default 20)
+    --replay FILE         replay sites of a percolation file instead of random ones,
+                          the file sets the side size so --size cant be given
+    --implementation I    brute-force, union-find or hack-union-find (default union-find)
+    --seed S              seed of the random sites (default random)
+    --batch B             number of sites opened between redraws (default 1)
+    --delay MS            milliseconds between redraws (default 50)
+    --all                 keep opening sites after the system percolates
+    --help                print this message";
+
+const CLEAR_SCREEN: &'static str = "\x1b[2J";
+const CURSOR_HOME: &'static str = "\x1b[H";
+const HIDE_CURSOR: &'static str = "\x1b[?25l";
+const SHOW_CURSOR: &'static str = "\x1b[?25h";
+const CLEAR_LINE: &'static str = "\x1b[K";
+
+struct Options {
+    size: Option<usize>,
+    replay: Option<String>,
+    implementation: Implementation,
+    seed: u64,
+    batch: usize,
+    delay: u64,
+    all: bool
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("percolation-viz: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn parse_number<N: std::str::FromStr>(arg: &str, value: Option<String>) -> N {
+    match value {
+        Some(value) => match value.parse() {
+            Ok(number) => number,
+            Err(_) => fail(&format!("invalid value '{}' for {}", value, arg)),
+        },
+        None => fail(&format!("missing value for {}", arg)),
+    }
+}
+
+fn parse_options() -> Options {
+    let mut options = Options {
+        size: None,
+        replay: None,
+        implementation: Implementation::UnionFind,
+        seed: rand::random(),
+        batch: 1,
+        delay: 50,
+        all: false
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            },
+            "--size" => options.size = Some(parse_number(&arg, args.next())),
+            "--seed" => options.seed = parse_number(&arg, args.next()),
+            "--batch" => options.batch = parse_number(&arg, args.next()),
+            "--delay" => options.delay = parse_number(&arg, args.next()),
+            "--replay" => match args.next() {
+                Some(path) => options.replay = Some(path),
+                None => fail("missing value for --replay"),
+            },
+            "--implementation" => match args.next().map(|name| name.parse::<Implementation>()) {
+                Some(Ok(implementation)) => options.implementation = implementation,
+                Some(Err(e)) => fail(&format!("{}", e)),
+                None => fail("missing value for --implementation"),
+            },
+            "--all" => options.all = true,
+            _ => fail(&format!("unknown option {}", arg)),
+        }
+    }
+    if options.size == Some(0) || options.batch == 0 {
+        fail("size and batch must be positive");
+    }
+    if options.size.is_some() && options.replay.is_some() {
+        fail("--size cant be combined with --replay, the replay file sets the side size");
+    }
+    options
+}
+
+fn sites(options: &Options) -> (usize, Vec<(usize, usize)>) {
+    match options.replay {
+        Some(ref path) => {
+            let file = match File::open(path) {
+                Ok(file) => file,
+                Err(e) => fail(&format!("cant open {}: {}", path, e)),
+            };
+            match read_sites(BufReader::new(file)) {
+                Ok(data) => data,
+                Err(e) => fail(&format!("cant read {}: {}", path, e)),
+            }
+        },
+        None => {
+            let size = options.size.unwrap_or(20);
+            let mut rng = trial_rng(options.seed, 0);
+            let sites = random_order(size * size, &mut rng).into_iter()
+                .map(|index| (index / size + 1, index % size + 1))
+                .collect();
+            (size, sites)
+        },
+    }
+}
+
+// Ctrl-C would otherwise leave the terminal without a cursor.
+#[cfg(unix)]
+mod interrupt {
+    use std::os::raw::{c_int, c_void};
+
+    const SIGINT: c_int = 2;
+
+    extern "C" {
+        fn signal(signum: c_int, handler: extern "C" fn(c_int)) -> usize;
+        fn write(fd: c_int, buf: *const c_void, count: usize) -> isize;
+        fn _exit(status: c_int) -> !;
+    }
+
+    // Only async-signal-safe calls are allowed here, so the cursor goes straight to the descriptor.
+    extern "C" fn restore_cursor(_: c_int) {
+        let show_cursor = super::SHOW_CURSOR.as_bytes();
+        unsafe {
+            write(1, show_cursor.as_ptr() as *const c_void, show_cursor.len());
+            _exit(130);
+        }
+    }
+
+    pub fn restore_cursor_on_interrupt() {
+        unsafe {
+            signal(SIGINT, restore_cursor);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+mod interrupt {
+
+    pub fn restore_cursor_on_interrupt() {}
+}
+
+fn draw<W: Write>(out: &mut W, percolation: &Percolation, highlighted: &[(usize, usize)], status: &str) -> io::Result<()> {
+    write!(out, "{}{}{}{}\n", CURSOR_HOME, to_ansi(percolation, highlighted), status, CLEAR_LINE)?;
+    out.flush()
+}
+
+fn main() {
+    let options = parse_options();
+    let (side_size, sites) = sites(&options);
+    let mut percolation = match options.implementation.try_create(side_size) {
+        Ok(percolation) => percolation,
+        Err(e) => fail(&format!("{}", e)),
+    };
+    let length = (side_size * side_size) as f64;
+    let delay = Duration::from_millis(options.delay);
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    interrupt::restore_cursor_on_interrupt();
+    let result = write!(out, "{}{}", CLEAR_SCREEN, HIDE_CURSOR).and_then(|_| {
+        let mut percolated = false;
+        for batch in sites.chunks(options.batch) {
+            for &(row, col) in batch {
+                if let Err(e) = percolation.try_open(row, col) {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, e));
+                }
+            }
+            let open_sites = percolation.number_of_open_sites();
+            if !percolated && percolation.percolates() {
+                percolated = true;
+                let path = percolation.percolating_path().unwrap_or_default();
+                let status = format!("\x1b[1;33mpercolates after {} open sites, p = {:.4}\x1b[0m",
+                    open_sites, open_sites as f64 / length);
+                draw(&mut out, &*percolation, &path, &status)?;
+                if !options.all {
+                    return Ok(());
+                }
+                thread::sleep(delay * 20);
+            }
+            else {
+                let status = format!("open sites {}, p = {:.4}", open_sites, open_sites as f64 / length);
+                draw(&mut out, &*percolation, &[], &status)?;
+                thread::sleep(delay);
+            }
+        }
+        Ok(())
+    });
+    let _ = write!(out, "{}", SHOW_CURSOR);
+    if let Err(e) = result {
+        eprintln!("percolation-viz: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,82 @@
+extern crate algorithms;
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process;
+
+use algorithms::union_find::{UnionFind, QuickFind, QuickUnion, WeightedQuickUnion, PathCompressionWeightedQuickUnion,
+    pairs, connect_pairs};
+
+const USAGE: &'static str = "\
+usage: union-find [--implementation I] [file]
+
+reads element count and then 'p q' pairs from file or stdin, prints every pair
+that was not connected yet and finally the number of components
+
+options:
+    --implementation I    quick-find, quick-union, weighted-quick-union or
+                          path-compression-weighted-quick-union (default weighted-quick-union)
+    --help                print this message";
+
+fn create(name: &str, size: usize) -> Option<Box<UnionFind>> {
+    match name {
+        "quick-find" => Some(Box::new(QuickFind::new(size))),
+        "quick-union" => Some(Box::new(QuickUnion::new(size))),
+        "weighted-quick-union" => Some(Box::new(WeightedQuickUnion::new(size))),
+        "path-compression-weighted-quick-union" => Some(Box::new(PathCompressionWeightedQuickUnion::new(size))),
+        _ => None,
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("union-find: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn main() {
+    let mut implementation = "weighted-quick-union".to_owned();
+    let mut path = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                return;
+            },
+            "--implementation" => match args.next() {
+                Some(name) => implementation = name,
+                None => fail("missing value for --implementation"),
+            },
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(arg),
+            _ => fail(&format!("unexpected argument {}", arg)),
+        }
+    }
+
+    let stdin = io::stdin();
+    let reader: Box<BufRead> = match path {
+        Some(ref path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(e) => fail(&format!("cant open {}: {}", path, e)),
+        },
+        None => Box::new(stdin.lock()),
+    };
+    let (size, pairs) = match pairs(reader) {
+        Ok(data) => data,
+        Err(e) => fail(&format!("cant read pairs: {}", e)),
+    };
+    let mut union_find = match create(&implementation, size) {
+        Some(union_find) => union_find,
+        None => fail(&format!("unknown union find implementation '{}'", implementation)),
+    };
+
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+    let result = connect_pairs(&mut *union_find, size, pairs, &mut out)
+        .and_then(|components| writeln!(out, "{} components", components));
+    if let Err(e) = result {
+        let _ = out.flush();
+        eprintln!("union-find: {}", e);
+        process::exit(1);
+    }
+}
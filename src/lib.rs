@@ -1,6 +1,9 @@
 extern crate fnv;
+extern crate rand;
 
 pub mod union_find;
 pub mod percolation;
 pub mod generator;
 pub mod collinear_points;
+
+mod number_reader;
@@ -0,0 +1,72 @@
+use std::io::{self, BufRead, Lines};
+
+// A count on the first line followed by one pair of numbers per line, blank lines are skipped.
+pub fn pairs<R: BufRead>(reader: R, count: &str, first: &'static str, second: &'static str)
+        -> io::Result<(usize, Pairs<R>)> {
+    let mut lines = reader.lines();
+    let count = match lines.next() {
+        Some(line) => parse_number(line?.trim(), count)?,
+        None => return Err(invalid_data(format!("where is {}?", count))),
+    };
+    let pairs = Pairs {
+        lines: lines,
+        first: first,
+        second: second
+    };
+    Ok((count, pairs))
+}
+
+pub fn read_pairs<R: BufRead>(reader: R, count: &str, first: &'static str, second: &'static str)
+        -> io::Result<(usize, Vec<(usize, usize)>)> {
+    let (count, pairs) = pairs(reader, count, first, second)?;
+    Ok((count, pairs.collect::<io::Result<Vec<_>>>()?))
+}
+
+pub struct Pairs<R> {
+    lines: Lines<R>,
+    first: &'static str,
+    second: &'static str
+}
+
+impl<R: BufRead> Pairs<R> {
+
+    fn parse_line(&self, line: &str) -> io::Result<Option<(usize, usize)>> {
+        let mut split = line.split_whitespace();
+        let p = match split.next() {
+            Some(s) => parse_number(s, self.first)?,
+            None => return Ok(None),
+        };
+        let q = match split.next() {
+            Some(s) => parse_number(s, self.second)?,
+            None => return Err(invalid_data(format!("where is {} in '{}'?", self.second, line))),
+        };
+        if split.next().is_some() {
+            return Err(invalid_data(format!("unexpected data after {} in '{}'", self.second, line)));
+        }
+        Ok(Some((p, q)))
+    }
+}
+
+impl<R: BufRead> Iterator for Pairs<R> {
+
+    type Item = io::Result<(usize, usize)>;
+
+    fn next(&mut self) -> Option<io::Result<(usize, usize)>> {
+        while let Some(line) = self.lines.next() {
+            match line.and_then(|line| self.parse_line(&line)) {
+                Ok(Some(pair)) => return Some(Ok(pair)),
+                Ok(None) => continue,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        None
+    }
+}
+
+pub fn parse_number(s: &str, what: &str) -> io::Result<usize> {
+    s.parse::<usize>().map_err(|_| invalid_data(format!("cant parse {} from '{}'", what, s)))
+}
+
+pub fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
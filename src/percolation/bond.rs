@@ -0,0 +1,188 @@
+use union_find::{UnionFind, CompactRankedQuickUnion};
+
+use super::{PercolationError, check_side_size};
+
+pub struct BondPercolation {
+    union_find: CompactRankedQuickUnion,
+    virtual_cell: usize,
+    horizontal_bonds: Vec<bool>,
+    vertical_bonds: Vec<bool>,
+    fullness: Vec<bool>,
+    side_size: usize,
+    percolated: bool
+}
+
+impl BondPercolation {
+
+    pub fn new(side_size: usize) -> BondPercolation {
+        match BondPercolation::try_new(side_size) {
+            Ok(percolation) => percolation,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_new(side_size: usize) -> Result<BondPercolation, PercolationError> {
+        check_side_size(side_size)?;
+        let length = side_size * side_size;
+        let virtual_cell = length;
+        let mut union_find = CompactRankedQuickUnion::new(length + 1);
+        let mut fullness = vec![false; length];
+        for p in 0..side_size {
+            union_find.union(p, virtual_cell);
+            fullness[p] = true;
+        }
+        Ok(BondPercolation {
+            union_find: union_find,
+            virtual_cell: virtual_cell,
+            horizontal_bonds: vec![false; length],
+            vertical_bonds: vec![false; length],
+            fullness: fullness,
+            side_size: side_size,
+            percolated: side_size == 1
+        })
+    }
+
+    pub fn side_size(&self) -> usize {
+        self.side_size
+    }
+
+    pub fn number_of_bonds(&self) -> usize {
+        2 * self.side_size * (self.side_size - 1)
+    }
+
+    pub fn open_bond(&mut self, first: (usize, usize), second: (usize, usize)) {
+        if !self.is_bond_open(first, second) {
+            let p = self.cell_index(first.0, first.1);
+            let q = self.cell_index(second.0, second.1);
+            *self.bond_mut(p, q) = true;
+            self.union_find.union(p, q);
+            if self.union_find.connected(p, self.virtual_cell) {
+                let index = if self.fullness[p] { q } else { p };
+                if !self.fullness[index] {
+                    self.make_full(index);
+                    self.fill_neighbors(index);
+                }
+            }
+        }
+    }
+
+    pub fn is_bond_open(&self, first: (usize, usize), second: (usize, usize)) -> bool {
+        let p = self.cell_index(first.0, first.1);
+        let q = self.cell_index(second.0, second.1);
+        self.bond(p, q)
+    }
+
+    pub fn try_open_bond(&mut self, first: (usize, usize), second: (usize, usize)) -> Result<(), PercolationError> {
+        self.check_bond(first, second)?;
+        self.open_bond(first, second);
+        Ok(())
+    }
+
+    pub fn try_is_bond_open(&self, first: (usize, usize), second: (usize, usize)) -> Result<bool, PercolationError> {
+        self.check_bond(first, second)?;
+        Ok(self.is_bond_open(first, second))
+    }
+
+    pub fn try_is_full(&self, row: usize, col: usize) -> Result<bool, PercolationError> {
+        self.check_site(row, col)?;
+        Ok(self.is_full(row, col))
+    }
+
+    pub fn is_full(&self, row: usize, col: usize) -> bool {
+        let index = self.cell_index(row, col);
+        self.fullness[index]
+    }
+
+    pub fn percolates(&self) -> bool {
+        self.percolated
+    }
+
+    #[inline]
+    fn cell_index(&self, row: usize, col: usize) -> usize {
+        (row - 1) * self.side_size + col - 1
+    }
+
+    fn check_site(&self, row: usize, col: usize) -> Result<(), PercolationError> {
+        if row < 1 || row > self.side_size || col < 1 || col > self.side_size {
+            Err(PercolationError::OutOfBounds { row: row, col: col, side_size: self.side_size })
+        }
+        else {
+            Ok(())
+        }
+    }
+
+    fn check_bond(&self, first: (usize, usize), second: (usize, usize)) -> Result<(), PercolationError> {
+        self.check_site(first.0, first.1)?;
+        self.check_site(second.0, second.1)?;
+        let rows = if first.0 < second.0 { second.0 - first.0 } else { first.0 - second.0 };
+        let cols = if first.1 < second.1 { second.1 - first.1 } else { first.1 - second.1 };
+        if rows + cols == 1 {
+            Ok(())
+        }
+        else {
+            Err(PercolationError::NotNeighbors { first: first, second: second })
+        }
+    }
+
+    fn bond(&self, p: usize, q: usize) -> bool {
+        let (p, q) = if p < q { (p, q) } else { (q, p) };
+        if q == p + 1 && q % self.side_size != 0 {
+            self.horizontal_bonds[p]
+        }
+        else if q == p + self.side_size {
+            self.vertical_bonds[p]
+        }
+        else {
+            panic!("sites {} and {} are not neighbors", p, q)
+        }
+    }
+
+    fn bond_mut(&mut self, p: usize, q: usize) -> &mut bool {
+        let (p, q) = if p < q { (p, q) } else { (q, p) };
+        if q == p + 1 && q % self.side_size != 0 {
+            &mut self.horizontal_bonds[p]
+        }
+        else if q == p + self.side_size {
+            &mut self.vertical_bonds[p]
+        }
+        else {
+            panic!("sites {} and {} are not neighbors", p, q)
+        }
+    }
+
+    fn open_neighbors(&self, index: usize) -> Vec<usize> {
+        let mut neighbors = Vec::with_capacity(4);
+        if index >= self.side_size && self.vertical_bonds[index - self.side_size] {
+            neighbors.push(index - self.side_size);
+        }
+        if index % self.side_size > 0 && self.horizontal_bonds[index - 1] {
+            neighbors.push(index - 1);
+        }
+        if index % self.side_size < self.side_size - 1 && self.horizontal_bonds[index] {
+            neighbors.push(index + 1);
+        }
+        if index < self.side_size * (self.side_size - 1) && self.vertical_bonds[index] {
+            neighbors.push(index + self.side_size);
+        }
+        neighbors
+    }
+
+    fn make_full(&mut self, index: usize) {
+        self.fullness[index] = true;
+        if index >= self.side_size * (self.side_size - 1) {
+            self.percolated = true;
+        }
+    }
+
+    fn fill_neighbors(&mut self, index: usize) {
+        let mut stack = vec![index];
+        while let Some(index) = stack.pop() {
+            for neighbor in self.open_neighbors(index) {
+                if !self.fullness[neighbor] {
+                    self.make_full(neighbor);
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+
+use union_find::{UnionFind, WeightedQuickUnion};
+
+use super::Percolation;
+use super::listener::PercolationListener;
+
+pub struct ClusterStats {
+    union_find: WeightedQuickUnion,
+    openness: Vec<bool>,
+    touches_top: Vec<bool>,
+    touches_bottom: Vec<bool>,
+    size_distribution: BTreeMap<usize, usize>,
+    side_size: usize,
+    open_sites: usize,
+    clusters: usize,
+    largest_cluster: usize,
+    spanning_mass: usize,
+    squared_sizes: usize,
+    spanning_squared_sizes: usize
+}
+
+impl ClusterStats {
+
+    pub fn new(side_size: usize) -> ClusterStats {
+        let length = side_size * side_size;
+        ClusterStats {
+            union_find: WeightedQuickUnion::new(length),
+            openness: vec![false; length],
+            touches_top: vec![false; length],
+            touches_bottom: vec![false; length],
+            size_distribution: BTreeMap::new(),
+            side_size: side_size,
+            open_sites: 0,
+            clusters: 0,
+            largest_cluster: 0,
+            spanning_mass: 0,
+            squared_sizes: 0,
+            spanning_squared_sizes: 0
+        }
+    }
+
+    pub fn from_percolation<P: Percolation + ?Sized>(percolation: &P) -> ClusterStats {
+        let side_size = percolation.side_size();
+        let mut stats = ClusterStats::new(side_size);
+        for row in 1..side_size + 1 {
+            for col in 1..side_size + 1 {
+                if percolation.is_open(row, col) {
+                    stats.open(row, col);
+                }
+            }
+        }
+        stats
+    }
+
+    pub fn open(&mut self, row: usize, col: usize) {
+        let index = (row - 1) * self.side_size + col - 1;
+        if self.openness[index] {
+            return;
+        }
+        self.openness[index] = true;
+        self.open_sites += 1;
+        self.clusters += 1;
+        self.touches_top[index] = row == 1;
+        self.touches_bottom[index] = row == self.side_size;
+        self.add_cluster(index);
+        if row > 1 {
+            self.merge(index, index - self.side_size);
+        }
+        if row < self.side_size {
+            self.merge(index, index + self.side_size);
+        }
+        if col > 1 {
+            self.merge(index, index - 1);
+        }
+        if col < self.side_size {
+            self.merge(index, index + 1);
+        }
+    }
+
+    pub fn close(&mut self, row: usize, col: usize) {
+        let index = (row - 1) * self.side_size + col - 1;
+        if !self.openness[index] {
+            return;
+        }
+        // A union find cant split a cluster, so the remaining open sites are merged again.
+        let mut openness = ::std::mem::replace(&mut self.openness, Vec::new());
+        openness[index] = false;
+        let side_size = self.side_size;
+        *self = ClusterStats::new(side_size);
+        for (index, open) in openness.into_iter().enumerate() {
+            if open {
+                self.open(index / side_size + 1, index % side_size + 1);
+            }
+        }
+    }
+
+    pub fn size_distribution(&self) -> &BTreeMap<usize, usize> {
+        &self.size_distribution
+    }
+
+    pub fn number_of_open_sites(&self) -> usize {
+        self.open_sites
+    }
+
+    pub fn number_of_clusters(&self) -> usize {
+        self.clusters
+    }
+
+    pub fn largest_cluster_size(&self) -> usize {
+        self.largest_cluster
+    }
+
+    pub fn spanning_cluster_mass(&self) -> usize {
+        self.spanning_mass
+    }
+
+    pub fn mean_cluster_size(&self) -> f64 {
+        let mass = self.open_sites - self.spanning_mass;
+        if mass == 0 {
+            0.0
+        }
+        else {
+            (self.squared_sizes - self.spanning_squared_sizes) as f64 / mass as f64
+        }
+    }
+
+    fn merge(&mut self, index: usize, neighbor: usize) {
+        if !self.openness[neighbor] {
+            return;
+        }
+        let index_root = self.union_find.find(index);
+        let neighbor_root = self.union_find.find(neighbor);
+        if index_root == neighbor_root {
+            return;
+        }
+        self.remove_cluster(index_root);
+        self.remove_cluster(neighbor_root);
+        self.union_find.union(index_root, neighbor_root);
+        let root = self.union_find.find(index_root);
+        self.touches_top[root] = self.touches_top[index_root] || self.touches_top[neighbor_root];
+        self.touches_bottom[root] = self.touches_bottom[index_root] || self.touches_bottom[neighbor_root];
+        self.clusters -= 1;
+        self.add_cluster(root);
+    }
+
+    fn add_cluster(&mut self, root: usize) {
+        let size = self.union_find.size(root);
+        *self.size_distribution.entry(size).or_insert(0) += 1;
+        self.squared_sizes += size * size;
+        if self.touches_top[root] && self.touches_bottom[root] {
+            self.spanning_mass += size;
+            self.spanning_squared_sizes += size * size;
+        }
+        if size > self.largest_cluster {
+            self.largest_cluster = size;
+        }
+    }
+
+    fn remove_cluster(&mut self, root: usize) {
+        let size = self.union_find.size(root);
+        let empty = match self.size_distribution.get_mut(&size) {
+            Some(count) => {
+                *count -= 1;
+                *count == 0
+            },
+            None => false,
+        };
+        if empty {
+            self.size_distribution.remove(&size);
+        }
+        self.squared_sizes -= size * size;
+        if self.touches_top[root] && self.touches_bottom[root] {
+            self.spanning_mass -= size;
+            self.spanning_squared_sizes -= size * size;
+        }
+    }
+}
+
+impl PercolationListener for ClusterStats {
+
+    fn on_open(&mut self, row: usize, col: usize) {
+        self.open(row, col);
+    }
+
+    fn on_close(&mut self, row: usize, col: usize) {
+        self.close(row, col);
+    }
+}
@@ -0,0 +1,125 @@
+use union_find::{UnionFind, CompactRankedQuickUnion};
+
+use super::{PercolationError, check_side_size};
+
+pub struct Percolation3D {
+    union_find: CompactRankedQuickUnion,
+    virtual_cell: usize,
+    openness: Vec<bool>,
+    fullness: Vec<bool>,
+    side_size: usize,
+    percolated: bool
+}
+
+impl Percolation3D {
+
+    pub fn new(side_size: usize) -> Percolation3D {
+        match Percolation3D::try_new(side_size) {
+            Ok(percolation) => percolation,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_new(side_size: usize) -> Result<Percolation3D, PercolationError> {
+        check_side_size(side_size)?;
+        let length = side_size * side_size * side_size;
+        let virtual_cell = length;
+        let mut union_find = CompactRankedQuickUnion::new(length + 1);
+        for p in 0..side_size * side_size {
+            union_find.union(p, virtual_cell);
+        }
+        Ok(Percolation3D {
+            union_find: union_find,
+            virtual_cell: virtual_cell,
+            openness: vec![false; length],
+            fullness: vec![false; length],
+            side_size: side_size,
+            percolated: false
+        })
+    }
+
+    pub fn side_size(&self) -> usize {
+        self.side_size
+    }
+
+    pub fn open(&mut self, layer: usize, row: usize, col: usize) {
+        if !self.is_open(layer, row, col) {
+            let index = self.cell_index(layer, row, col);
+            self.openness[index] = true;
+            let mut union = false;
+            for neighbor in self.neighbors(index) {
+                if self.openness[neighbor] {
+                    self.union_find.union(index, neighbor);
+                    union = true;
+                }
+            }
+            if (union || layer == 1) && self.union_find.connected(index, self.virtual_cell) {
+                self.make_full(index);
+                self.fill_neighbors(index);
+            }
+        }
+    }
+
+    pub fn is_open(&self, layer: usize, row: usize, col: usize) -> bool {
+        let index = self.cell_index(layer, row, col);
+        self.openness[index]
+    }
+
+    pub fn is_full(&self, layer: usize, row: usize, col: usize) -> bool {
+        let index = self.cell_index(layer, row, col);
+        self.fullness[index]
+    }
+
+    pub fn percolates(&self) -> bool {
+        self.percolated
+    }
+
+    #[inline]
+    fn cell_index(&self, layer: usize, row: usize, col: usize) -> usize {
+        ((layer - 1) * self.side_size + row - 1) * self.side_size + col - 1
+    }
+
+    fn neighbors(&self, index: usize) -> Vec<usize> {
+        let side_size = self.side_size;
+        let layer_size = side_size * side_size;
+        let mut neighbors = Vec::with_capacity(6);
+        if index >= layer_size {
+            neighbors.push(index - layer_size);
+        }
+        if index / layer_size < side_size - 1 {
+            neighbors.push(index + layer_size);
+        }
+        if index % layer_size >= side_size {
+            neighbors.push(index - side_size);
+        }
+        if index % layer_size < layer_size - side_size {
+            neighbors.push(index + side_size);
+        }
+        if index % side_size > 0 {
+            neighbors.push(index - 1);
+        }
+        if index % side_size < side_size - 1 {
+            neighbors.push(index + 1);
+        }
+        neighbors
+    }
+
+    fn make_full(&mut self, index: usize) {
+        self.fullness[index] = true;
+        if index / (self.side_size * self.side_size) == self.side_size - 1 {
+            self.percolated = true;
+        }
+    }
+
+    fn fill_neighbors(&mut self, index: usize) {
+        let mut stack = vec![index];
+        while let Some(index) = stack.pop() {
+            for neighbor in self.neighbors(index) {
+                if self.openness[neighbor] && !self.fullness[neighbor] {
+                    self.make_full(neighbor);
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use super::{Percolation, PercolationError, check_side_size};
+use super::ext::{PercolationBase, Ext};
+use super::listener::{PercolationListener, NoListener};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Directions {
+    pub up: bool,
+    pub down: bool,
+    pub left: bool,
+    pub right: bool
+}
+
+impl Directions {
+
+    pub fn all() -> Directions {
+        Directions { up: true, down: true, left: true, right: true }
+    }
+
+    pub fn down_and_sideways() -> Directions {
+        Directions { up: false, down: true, left: true, right: true }
+    }
+
+    pub fn down_and_right() -> Directions {
+        Directions { up: false, down: true, left: false, right: true }
+    }
+
+    pub fn down() -> Directions {
+        Directions { up: false, down: true, left: false, right: false }
+    }
+}
+
+pub struct DirectedPercolation<L = NoListener> {
+    base: PercolationBase,
+    directions: Directions,
+    listener: L
+}
+
+impl DirectedPercolation {
+
+    pub fn new(side_size: usize, directions: Directions) -> DirectedPercolation {
+        DirectedPercolation::with_listener(side_size, directions, NoListener)
+    }
+
+    pub fn try_new(side_size: usize, directions: Directions) -> Result<DirectedPercolation, PercolationError> {
+        DirectedPercolation::try_with_listener(side_size, directions, NoListener)
+    }
+}
+
+impl<L: PercolationListener> DirectedPercolation<L> {
+
+    pub fn with_listener(side_size: usize, directions: Directions, listener: L) -> DirectedPercolation<L> {
+        match DirectedPercolation::try_with_listener(side_size, directions, listener) {
+            Ok(percolation) => percolation,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_with_listener(side_size: usize, directions: Directions, listener: L)
+            -> Result<DirectedPercolation<L>, PercolationError> {
+        check_side_size(side_size)?;
+        Ok(DirectedPercolation {
+            base: PercolationBase::new(side_size),
+            directions: directions,
+            listener: listener
+        })
+    }
+
+    pub fn directions(&self) -> Directions {
+        self.directions
+    }
+
+    pub fn listener(&self) -> &L {
+        &self.listener
+    }
+
+    pub fn listener_mut(&mut self) -> &mut L {
+        &mut self.listener
+    }
+
+    fn is_fed(&self, index: usize) -> bool {
+        let side_size = self.base.side_size();
+        index < side_size
+            || self.directions.down && self.base.has_top_neighbor(index)
+                && self.base.is_full_by_index(index - side_size)
+            || self.directions.up && self.base.has_bottom_neighbor(index)
+                && self.base.is_full_by_index(index + side_size)
+            || self.directions.right && self.base.has_left_neighbor(index)
+                && self.base.is_full_by_index(index - 1)
+            || self.directions.left && self.base.has_right_neighbor(index)
+                && self.base.is_full_by_index(index + 1)
+    }
+}
+
+impl<L: PercolationListener> Percolation for DirectedPercolation<L> {
+
+    fn open(&mut self, row: usize, col: usize) {
+        if !self.is_open(row, col) {
+            let index = self.base.cell_index(row, col);
+            self.base.make_open(index);
+            self.listener.on_open(row, col);
+            if self.is_fed(index) {
+                self.base.make_full(index, &mut self.listener);
+                self.base.fill_towards(index, self.directions, &mut self.listener);
+            }
+        }
+    }
+
+    fn is_open(&self, row: usize, col: usize) -> bool {
+        let index = self.base.cell_index(row, col);
+        self.base.is_open_by_index(index)
+    }
+
+    fn is_full(&self, row: usize, col: usize) -> bool {
+        let index = self.base.cell_index(row, col);
+        self.base.is_full_by_index(index)
+    }
+
+    fn percolates(&self) -> bool {
+        self.base.percolated()
+    }
+
+    fn side_size(&self) -> usize {
+        self.base.side_size()
+    }
+
+    fn number_of_open_sites(&self) -> usize {
+        self.base.number_of_open_sites()
+    }
+}
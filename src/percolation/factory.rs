@@ -0,0 +1,81 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{Percolation, PercolationError, BruteForcePercolation, UnionFindPercolation, HackUnionFindPercolation};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Implementation {
+    BruteForce,
+    UnionFind,
+    HackUnionFind
+}
+
+impl Implementation {
+
+    pub fn all() -> [Implementation; 3] {
+        [Implementation::BruteForce, Implementation::UnionFind, Implementation::HackUnionFind]
+    }
+
+    pub fn create(self, side_size: usize) -> Box<Percolation> {
+        match self.try_create(side_size) {
+            Ok(percolation) => percolation,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_create(self, side_size: usize) -> Result<Box<Percolation>, PercolationError> {
+        Ok(match self {
+            Implementation::BruteForce => Box::new(BruteForcePercolation::try_new(side_size)?),
+            Implementation::UnionFind => Box::new(UnionFindPercolation::try_new(side_size)?),
+            Implementation::HackUnionFind => Box::new(HackUnionFindPercolation::try_new(side_size)?),
+        })
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Implementation::BruteForce => "brute-force",
+            Implementation::UnionFind => "union-find",
+            Implementation::HackUnionFind => "hack-union-find",
+        }
+    }
+}
+
+impl fmt::Display for Implementation {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Implementation {
+    type Err = ParseImplementationError;
+
+    fn from_str(s: &str) -> Result<Implementation, ParseImplementationError> {
+        let name = s.trim().to_lowercase().replace('_', "-");
+        Implementation::all().iter()
+            .find(|implementation| implementation.name() == name)
+            .cloned()
+            .ok_or_else(|| ParseImplementationError { name: s.to_owned() })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseImplementationError {
+    name: String
+}
+
+impl fmt::Display for ParseImplementationError {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown percolation implementation '{}', expected one of brute-force, union-find, hack-union-find",
+            self.name)
+    }
+}
+
+impl Error for ParseImplementationError {
+
+    fn description(&self) -> &str {
+        "unknown percolation implementation"
+    }
+}
@@ -0,0 +1,226 @@
+use union_find::{UnionFind, CompactRankedQuickUnion};
+
+use super::PercolationError;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Model {
+    Site,
+    Bond,
+    SiteBond
+}
+
+pub struct GraphPercolation {
+    union_find: CompactRankedQuickUnion,
+    virtual_cell: usize,
+    adjacency: Vec<Vec<(usize, usize)>>,
+    edges: Vec<(usize, usize)>,
+    open_sites: Vec<bool>,
+    open_bonds: Vec<bool>,
+    fullness: Vec<bool>,
+    is_source: Vec<bool>,
+    is_sink: Vec<bool>,
+    percolated: bool
+}
+
+impl GraphPercolation {
+
+    pub fn new(vertices: usize, edges: &[(usize, usize)], sources: &[usize], sinks: &[usize], model: Model)
+            -> GraphPercolation {
+        match GraphPercolation::try_new(vertices, edges, sources, sinks, model) {
+            Ok(percolation) => percolation,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_new(vertices: usize, edges: &[(usize, usize)], sources: &[usize], sinks: &[usize], model: Model)
+            -> Result<GraphPercolation, PercolationError> {
+        let mut adjacency = vec![Vec::new(); vertices];
+        for (edge, &(p, q)) in edges.iter().enumerate() {
+            check_vertex(vertices, p)?;
+            check_vertex(vertices, q)?;
+            adjacency[p].push((q, edge));
+            if p != q {
+                adjacency[q].push((p, edge));
+            }
+        }
+        let mut is_source = vec![false; vertices];
+        for &vertex in sources {
+            check_vertex(vertices, vertex)?;
+            is_source[vertex] = true;
+        }
+        let mut is_sink = vec![false; vertices];
+        for &vertex in sinks {
+            check_vertex(vertices, vertex)?;
+            is_sink[vertex] = true;
+        }
+        let mut percolation = GraphPercolation {
+            union_find: CompactRankedQuickUnion::new(vertices + 1),
+            virtual_cell: vertices,
+            adjacency: adjacency,
+            edges: edges.to_vec(),
+            open_sites: vec![false; vertices],
+            open_bonds: vec![model == Model::Site; edges.len()],
+            fullness: vec![false; vertices],
+            is_source: is_source,
+            is_sink: is_sink,
+            percolated: false
+        };
+        if model == Model::Bond {
+            for vertex in 0..vertices {
+                percolation.open_site(vertex);
+            }
+        }
+        Ok(percolation)
+    }
+
+    pub fn number_of_vertices(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn number_of_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    pub fn open_site(&mut self, vertex: usize) {
+        if self.open_sites[vertex] {
+            return;
+        }
+        self.open_sites[vertex] = true;
+        if self.is_source[vertex] {
+            self.union_find.union(vertex, self.virtual_cell);
+        }
+        for i in 0..self.adjacency[vertex].len() {
+            let (neighbor, edge) = self.adjacency[vertex][i];
+            if self.transmits(vertex, neighbor, edge) {
+                self.union_find.union(vertex, neighbor);
+            }
+        }
+        if self.union_find.connected(vertex, self.virtual_cell) {
+            self.fill(vertex);
+        }
+    }
+
+    pub fn open_bond(&mut self, edge: usize) {
+        if self.open_bonds[edge] {
+            return;
+        }
+        self.open_bonds[edge] = true;
+        let (p, q) = self.edges[edge];
+        if self.transmits(p, q, edge) {
+            self.union_find.union(p, q);
+            if self.union_find.connected(p, self.virtual_cell) {
+                self.fill(p);
+                self.fill(q);
+            }
+        }
+    }
+
+    pub fn try_open_site(&mut self, vertex: usize) -> Result<(), PercolationError> {
+        check_vertex(self.number_of_vertices(), vertex)?;
+        self.open_site(vertex);
+        Ok(())
+    }
+
+    pub fn try_open_bond(&mut self, edge: usize) -> Result<(), PercolationError> {
+        check_edge(self.number_of_edges(), edge)?;
+        self.open_bond(edge);
+        Ok(())
+    }
+
+    pub fn is_site_open(&self, vertex: usize) -> bool {
+        self.open_sites[vertex]
+    }
+
+    pub fn is_bond_open(&self, edge: usize) -> bool {
+        self.open_bonds[edge]
+    }
+
+    pub fn is_full(&self, vertex: usize) -> bool {
+        self.fullness[vertex]
+    }
+
+    pub fn try_is_site_open(&self, vertex: usize) -> Result<bool, PercolationError> {
+        check_vertex(self.number_of_vertices(), vertex)?;
+        Ok(self.is_site_open(vertex))
+    }
+
+    pub fn try_is_bond_open(&self, edge: usize) -> Result<bool, PercolationError> {
+        check_edge(self.number_of_edges(), edge)?;
+        Ok(self.is_bond_open(edge))
+    }
+
+    pub fn try_is_full(&self, vertex: usize) -> Result<bool, PercolationError> {
+        check_vertex(self.number_of_vertices(), vertex)?;
+        Ok(self.is_full(vertex))
+    }
+
+    pub fn percolates(&self) -> bool {
+        self.percolated
+    }
+
+    fn transmits(&self, p: usize, q: usize, edge: usize) -> bool {
+        self.open_bonds[edge] && self.open_sites[p] && self.open_sites[q]
+    }
+
+    fn fill(&mut self, vertex: usize) {
+        if self.fullness[vertex] {
+            return;
+        }
+        self.make_full(vertex);
+        let mut stack = vec![vertex];
+        while let Some(vertex) = stack.pop() {
+            for i in 0..self.adjacency[vertex].len() {
+                let (neighbor, edge) = self.adjacency[vertex][i];
+                if !self.fullness[neighbor] && self.transmits(vertex, neighbor, edge) {
+                    self.make_full(neighbor);
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+
+    fn make_full(&mut self, vertex: usize) {
+        self.fullness[vertex] = true;
+        if self.is_sink[vertex] {
+            self.percolated = true;
+        }
+    }
+}
+
+pub fn square_lattice(side_size: usize) -> Vec<(usize, usize)> {
+    let mut edges = Vec::new();
+    for row in 0..side_size {
+        for col in 0..side_size {
+            let index = row * side_size + col;
+            if col + 1 < side_size {
+                edges.push((index, index + 1));
+            }
+            if row + 1 < side_size {
+                edges.push((index, index + side_size));
+            }
+        }
+    }
+    edges
+}
+
+fn check_vertex(vertices: usize, vertex: usize) -> Result<(), PercolationError> {
+    if vertex >= vertices {
+        Err(PercolationError::NoSuchVertex { vertex: vertex, vertices: vertices })
+    }
+    else {
+        Ok(())
+    }
+}
+
+fn check_edge(edges: usize, edge: usize) -> Result<(), PercolationError> {
+    if edge >= edges {
+        Err(PercolationError::NoSuchEdge { edge: edge, edges: edges })
+    }
+    else {
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use number_reader::invalid_data;
+
+use super::{Percolation, PercolationError, UnionFindPercolation};
+
+pub struct Mask {
+    width: usize,
+    height: usize,
+    openness: Vec<bool>
+}
+
+impl Mask {
+
+    pub fn new(width: usize, height: usize, openness: Vec<bool>) -> Mask {
+        assert_eq!(Some(openness.len()), width.checked_mul(height), "there must be a state for every pixel");
+        Mask {
+            width: width,
+            height: height,
+            openness: openness
+        }
+    }
+
+    // White pixels of a bitmap are open sites, gray pixels are open when they are at least
+    // `threshold` of the maximum gray value, the same convention `render::write_pgm` follows.
+    pub fn read<R: Read>(reader: &mut R, threshold: f64) -> io::Result<Mask> {
+        if !(threshold >= 0.0 && threshold <= 1.0) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("threshold {} is out of 0..=1", threshold)));
+        }
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        let mut parser = Parser { data: &data, position: 0 };
+        let format = parser.magic()?;
+        let width = parser.number("width")?;
+        let height = parser.number("height")?;
+        if width == 0 || height == 0 {
+            return Err(invalid_data(format!("image of {}x{} pixels is empty", width, height)));
+        }
+        let length = width.checked_mul(height).ok_or_else(|| too_large(width, height))?;
+        let openness = match format {
+            b'1' => {
+                let mut openness = Vec::new();
+                while openness.len() < length {
+                    openness.push(parser.bit()? == 0);
+                }
+                openness
+            },
+            b'4' => {
+                parser.single_whitespace()?;
+                let row_bytes = width / 8 + if width % 8 == 0 { 0 } else { 1 };
+                let pixels = parser.bytes(row_bytes.checked_mul(height).ok_or_else(|| too_large(width, height))?)?;
+                (0..length)
+                    .map(|index| {
+                        let (row, col) = (index / width, index % width);
+                        pixels[row * row_bytes + col / 8] & (0x80 >> (col % 8)) == 0
+                    })
+                    .collect()
+            },
+            b'2' | b'5' => {
+                let max_value = parser.number("maximum gray value")?;
+                if max_value == 0 || max_value > 65535 {
+                    return Err(invalid_data(format!("maximum gray value {} is out of 1..65535", max_value)));
+                }
+                let open_from = threshold * max_value as f64;
+                let grays = if format == b'2' {
+                    let mut grays = Vec::new();
+                    while grays.len() < length {
+                        grays.push(parser.number("gray value")?);
+                    }
+                    grays
+                }
+                else {
+                    parser.single_whitespace()?;
+                    let depth = if max_value < 256 { 1 } else { 2 };
+                    let pixels = parser.bytes(length.checked_mul(depth).ok_or_else(|| too_large(width, height))?)?;
+                    pixels.chunks(depth).map(|bytes| bytes.iter().fold(0, |gray, &byte| gray << 8 | byte as usize)).collect()
+                };
+                grays.into_iter().map(|gray| gray as f64 >= open_from).collect()
+            },
+            _ => return Err(invalid_data(format!("unsupported image format P{}", format as char))),
+        };
+        Ok(Mask::new(width, height, openness))
+    }
+
+    pub fn open_file<P: AsRef<Path>>(path: P, threshold: f64) -> io::Result<Mask> {
+        let mut file = File::open(path)?;
+        Mask::read(&mut file, threshold)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_open(&self, row: usize, col: usize) -> bool {
+        self.openness[(row - 1) * self.width + col - 1]
+    }
+
+    pub fn number_of_open_sites(&self) -> usize {
+        self.openness.iter().filter(|&&open| open).count()
+    }
+
+    // Grids percolate between their own top and bottom rows, so an image is a sample of a grid
+    // exactly as high as the image, columns right of a narrower image remain blocked and change nothing.
+    pub fn apply<P: Percolation + ?Sized>(&self, percolation: &mut P) -> Result<(), PercolationError> {
+        let side_size = percolation.side_size();
+        if self.height != side_size || self.width > side_size {
+            return Err(PercolationError::MaskMismatch { width: self.width, height: self.height, side_size: side_size });
+        }
+        self.open_sites(percolation);
+        Ok(())
+    }
+
+    // Any image fits a grid as big as its longer side once the rest of the grid stays blocked,
+    // its top row is the source and its bottom row the sink, whatever the grid rows are.
+    pub fn to_sample(&self) -> UnionFindPercolation {
+        let side_size = ::std::cmp::max(self.width, self.height);
+        let sources = (1..self.width + 1).map(|col| (1, col)).collect::<Vec<_>>();
+        let sinks = (1..self.width + 1).map(|col| (self.height, col)).collect::<Vec<_>>();
+        let mut percolation = UnionFindPercolation::with_boundaries(side_size, &sources, &sinks);
+        self.open_sites(&mut percolation);
+        percolation
+    }
+
+    fn open_sites<P: Percolation + ?Sized>(&self, percolation: &mut P) {
+        for row in 1..self.height + 1 {
+            for col in 1..self.width + 1 {
+                if self.is_open(row, col) {
+                    percolation.open(row, col);
+                }
+            }
+        }
+    }
+
+    pub fn to_percolation<P, F>(&self, new: F) -> Result<P, PercolationError>
+            where P: Percolation, F: FnOnce(usize) -> P {
+        if self.width > self.height {
+            return Err(PercolationError::MaskMismatch { width: self.width, height: self.height, side_size: self.height });
+        }
+        let mut percolation = new(self.height);
+        self.apply(&mut percolation)?;
+        Ok(percolation)
+    }
+}
+
+struct Parser<'a> {
+    data: &'a [u8],
+    position: usize
+}
+
+impl<'a> Parser<'a> {
+
+    fn magic(&mut self) -> io::Result<u8> {
+        if self.data.len() < 2 || self.data[0] != b'P' {
+            return Err(invalid_data("not a netpbm image".to_owned()));
+        }
+        self.position = 2;
+        Ok(self.data[1])
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        while let Some(&byte) = self.data.get(self.position) {
+            if byte == b'#' {
+                while self.position < self.data.len() && self.data[self.position] != b'\n' {
+                    self.position += 1;
+                }
+            }
+            else if byte.is_ascii_whitespace() {
+                self.position += 1;
+            }
+            else {
+                break;
+            }
+        }
+    }
+
+    fn number(&mut self, what: &str) -> io::Result<usize> {
+        self.skip_whitespace_and_comments();
+        let start = self.position;
+        while self.position < self.data.len() && self.data[self.position].is_ascii_digit() {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Err(invalid_data(format!("where is {}?", what)));
+        }
+        let digits = String::from_utf8_lossy(&self.data[start..self.position]).into_owned();
+        digits.parse::<usize>().map_err(|_| invalid_data(format!("cant parse {} from '{}'", what, digits)))
+    }
+
+    fn bit(&mut self) -> io::Result<u8> {
+        self.skip_whitespace_and_comments();
+        match self.data.get(self.position) {
+            Some(&bit) if bit == b'0' || bit == b'1' => {
+                self.position += 1;
+                Ok(bit - b'0')
+            },
+            Some(&other) => Err(invalid_data(format!("unexpected '{}' among bitmap pixels", other as char))),
+            None => Err(truncated()),
+        }
+    }
+
+    fn single_whitespace(&mut self) -> io::Result<()> {
+        match self.data.get(self.position) {
+            Some(byte) if byte.is_ascii_whitespace() => {
+                self.position += 1;
+                Ok(())
+            },
+            _ => Err(invalid_data("where is whitespace before pixels?".to_owned())),
+        }
+    }
+
+    fn bytes(&mut self, count: usize) -> io::Result<&'a [u8]> {
+        let data = self.data;
+        let end = match self.position.checked_add(count) {
+            Some(end) => end,
+            None => return Err(truncated()),
+        };
+        match data.get(self.position..end) {
+            Some(bytes) => {
+                self.position += count;
+                Ok(bytes)
+            },
+            None => Err(truncated()),
+        }
+    }
+}
+
+fn too_large(width: usize, height: usize) -> io::Error {
+    invalid_data(format!("image of {}x{} pixels does not fit in memory", width, height))
+}
+
+fn truncated() -> io::Error {
+    io::Error::new(io::ErrorKind::UnexpectedEof, "image ends before all pixels are read")
+}
@@ -0,0 +1,218 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use rand::Rng;
+
+use union_find::{UnionFind, CompactRankedQuickUnion};
+
+use super::Percolation;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Trapping {
+    Disabled,
+    Enabled
+}
+
+#[derive(PartialEq)]
+struct Candidate {
+    strength: f64,
+    index: usize
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+
+    fn cmp(&self, other: &Candidate) -> Ordering {
+        other.strength.partial_cmp(&self.strength)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for Candidate {
+
+    fn partial_cmp(&self, other: &Candidate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct InvasionPercolation<P: Percolation> {
+    percolation: P,
+    strengths: Vec<f64>,
+    order: Vec<usize>,
+    trapped_at: Vec<usize>,
+    invaded: Vec<(usize, usize)>,
+    next: usize
+}
+
+impl<P: Percolation> InvasionPercolation<P> {
+
+    pub fn new<R: Rng>(percolation: P, trapping: Trapping, rng: &mut R) -> InvasionPercolation<P> {
+        let side_size = percolation.side_size();
+        let strengths = (0..side_size * side_size).map(|_| rng.gen::<f64>()).collect();
+        InvasionPercolation::with_strengths(percolation, strengths, trapping)
+    }
+
+    pub fn with_strengths(percolation: P, strengths: Vec<f64>, trapping: Trapping) -> InvasionPercolation<P> {
+        let side_size = percolation.side_size();
+        let length = side_size * side_size;
+        assert_eq!(strengths.len(), length, "there must be a strength for every site");
+        // Open sites would be neither invaded nor defended, so the invasion starts from an empty grid.
+        assert_eq!(percolation.number_of_open_sites(), 0, "invasion needs a percolation without open sites");
+        let order = invasion_order(side_size, &strengths);
+        let trapped_at = match trapping {
+            Trapping::Enabled => trapping_steps(side_size, &order),
+            Trapping::Disabled => vec![NEVER; length],
+        };
+        InvasionPercolation {
+            percolation: percolation,
+            strengths: strengths,
+            order: order,
+            trapped_at: trapped_at,
+            invaded: Vec::new(),
+            next: 0
+        }
+    }
+
+    pub fn step(&mut self) -> Option<(usize, usize)> {
+        while self.next < self.order.len() {
+            let index = self.order[self.next];
+            self.next += 1;
+            if self.trapped_at[index] < self.next - 1 {
+                continue;
+            }
+            let side_size = self.percolation.side_size();
+            let site = (index / side_size + 1, index % side_size + 1);
+            self.percolation.open(site.0, site.1);
+            self.invaded.push(site);
+            return Some(site);
+        }
+        None
+    }
+
+    pub fn run(&mut self) -> usize {
+        while !self.percolation.percolates() && self.step().is_some() {}
+        self.invaded.len()
+    }
+
+    pub fn percolation(&self) -> &P {
+        &self.percolation
+    }
+
+    pub fn into_percolation(self) -> P {
+        self.percolation
+    }
+
+    pub fn invaded(&self) -> &[(usize, usize)] {
+        &self.invaded
+    }
+
+    pub fn strength(&self, row: usize, col: usize) -> f64 {
+        self.strengths[self.index(row, col)]
+    }
+
+    pub fn is_trapped(&self, row: usize, col: usize) -> bool {
+        self.trapped_at[self.index(row, col)] < self.next
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        (row - 1) * self.percolation.side_size() + col - 1
+    }
+}
+
+const NEVER: usize = ::std::usize::MAX;
+
+// Invasion without trapping, every site in the order it falls.
+fn invasion_order(side_size: usize, strengths: &[f64]) -> Vec<usize> {
+    let mut on_perimeter = vec![false; strengths.len()];
+    let mut perimeter = BinaryHeap::new();
+    let mut order = Vec::with_capacity(strengths.len());
+    for index in 0..side_size {
+        on_perimeter[index] = true;
+        perimeter.push(Candidate { strength: strengths[index], index: index });
+    }
+    while let Some(Candidate { index, .. }) = perimeter.pop() {
+        order.push(index);
+        for neighbor in neighbors(side_size, index) {
+            if !on_perimeter[neighbor] {
+                on_perimeter[neighbor] = true;
+                perimeter.push(Candidate { strength: strengths[neighbor], index: neighbor });
+            }
+        }
+    }
+    order
+}
+
+// Trapped sites never open a way to other sites, so the untrapped sites fall in the same order as
+// without trapping. Adding sites back in reverse order grows the defended regions, and the step
+// that joins a region cut off from the bottom row to one reaching it is the step that traps it.
+fn trapping_steps(side_size: usize, order: &[usize]) -> Vec<usize> {
+    let length = order.len();
+    let outlet = side_size * (side_size - 1);
+    let mut trapped_at = vec![NEVER; length];
+    let mut union_find = CompactRankedQuickUnion::new(length);
+    let mut added = vec![false; length];
+    let mut reaches_outlet = (0..length).map(|index| index >= outlet).collect::<Vec<_>>();
+    let mut members = vec![Vec::new(); length];
+    for (step, &index) in order.iter().enumerate().rev() {
+        added[index] = true;
+        members[index].push(index);
+        let mut roots = neighbors(side_size, index).into_iter()
+            .filter(|&neighbor| added[neighbor])
+            .map(|neighbor| union_find.find(neighbor))
+            .collect::<Vec<_>>();
+        roots.sort();
+        roots.dedup();
+        let reaches = reaches_outlet[index] || roots.iter().any(|&root| reaches_outlet[root]);
+        if reaches {
+            for &root in roots.iter().filter(|&&root| !reaches_outlet[root]) {
+                for &member in members[root].iter() {
+                    trapped_at[member] = step;
+                }
+            }
+        }
+        for root in roots {
+            join(&mut union_find, &mut members, index, root);
+        }
+        let root = union_find.find(index);
+        reaches_outlet[root] = reaches;
+        if reaches {
+            members[root] = Vec::new();
+        }
+    }
+    trapped_at
+}
+
+fn join(union_find: &mut CompactRankedQuickUnion, members: &mut Vec<Vec<usize>>, p: usize, q: usize) {
+    let p_root = union_find.find(p);
+    let q_root = union_find.find(q);
+    if p_root == q_root {
+        return;
+    }
+    union_find.union(p_root, q_root);
+    let root = union_find.find(p_root);
+    let child = if root == p_root { q_root } else { p_root };
+    let mut moved = ::std::mem::replace(&mut members[child], Vec::new());
+    if moved.len() > members[root].len() {
+        ::std::mem::swap(&mut moved, &mut members[root]);
+    }
+    members[root].extend(moved);
+}
+
+fn neighbors(side_size: usize, index: usize) -> Vec<usize> {
+    let mut neighbors = Vec::with_capacity(4);
+    if index >= side_size {
+        neighbors.push(index - side_size);
+    }
+    if index % side_size > 0 {
+        neighbors.push(index - 1);
+    }
+    if index % side_size < side_size - 1 {
+        neighbors.push(index + 1);
+    }
+    if index < side_size * (side_size - 1) {
+        neighbors.push(index + side_size);
+    }
+    neighbors
+}
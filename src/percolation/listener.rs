@@ -0,0 +1,18 @@
+// Full and drain events fire only when a site changes between full and not full, so closing a
+// site reports the sites it cut off from the top and stays silent about the ones still reached.
+pub trait PercolationListener {
+
+    fn on_open(&mut self, _row: usize, _col: usize) {}
+
+    fn on_close(&mut self, _row: usize, _col: usize) {}
+
+    fn on_full(&mut self, _row: usize, _col: usize) {}
+
+    fn on_drain(&mut self, _row: usize, _col: usize) {}
+
+    fn on_percolate(&mut self) {}
+}
+
+pub struct NoListener;
+
+impl PercolationListener for NoListener {}
@@ -0,0 +1,990 @@
+use std::error::Error;
+use std::fmt;
+
+use union_find::{UnionFind, CompactRankedQuickUnion};
+use self::ext::{PercolationBase, Boundaries, Ext};
+use self::listener::{PercolationListener, NoListener};
+use self::sites::SiteStates;
+
+pub mod bond;
+pub mod clusters;
+pub mod cubic;
+pub mod directed;
+pub mod factory;
+pub mod graph;
+pub mod image;
+pub mod invasion;
+pub mod listener;
+pub mod newman_ziff;
+pub mod path;
+pub mod random_graph;
+pub mod render;
+pub mod replay;
+pub mod sites;
+pub mod snapshot;
+pub mod source_sink;
+pub mod stats;
+pub mod stats_config;
+pub mod undo;
+
+mod ext {
+    use super::PercolationError;
+    use super::directed::Directions;
+    use super::listener::{PercolationListener, NoListener};
+    use super::sites::SiteStates;
+
+    pub trait Ext {
+
+        fn cell_index(&self, row: usize, col: usize) -> usize;
+
+        fn fill_neighbors<L: PercolationListener>(&mut self, index: usize, listener: &mut L) {
+            self.fill_towards(index, Directions::all(), listener);
+        }
+
+        fn fill_towards<L: PercolationListener>(&mut self, index: usize, directions: Directions, listener: &mut L);
+
+        fn is_open_by_index(&self, index: usize) -> bool;
+
+        fn is_full_by_index(&self, index: usize) -> bool;
+
+        fn has_top_neighbor(&self, index: usize) -> bool;
+
+        fn has_left_neighbor(&self, index: usize) -> bool;
+
+        fn has_right_neighbor(&self, index: usize) -> bool;
+
+        fn has_bottom_neighbor(&self, index: usize) -> bool;
+
+        fn make_open(&mut self, index: usize);
+
+        fn make_full<L: PercolationListener>(&mut self, index: usize, listener: &mut L);
+    }
+
+    pub struct Boundaries {
+        sources: Vec<usize>,
+        is_source: Vec<bool>,
+        is_sink: Vec<bool>
+    }
+
+    impl Boundaries {
+
+        pub fn new(side_size: usize, sources: &[(usize, usize)], sinks: &[(usize, usize)])
+                -> Result<Boundaries, PercolationError> {
+            let length = side_size * side_size;
+            let mut boundaries = Boundaries {
+                sources: Vec::with_capacity(sources.len()),
+                is_source: vec![false; length],
+                is_sink: vec![false; length]
+            };
+            for &(row, col) in sources {
+                let index = site_index(side_size, row, col)?;
+                if !boundaries.is_source[index] {
+                    boundaries.is_source[index] = true;
+                    boundaries.sources.push(index);
+                }
+            }
+            for &(row, col) in sinks {
+                boundaries.is_sink[site_index(side_size, row, col)?] = true;
+            }
+            Ok(boundaries)
+        }
+
+        pub fn sources(&self) -> &[usize] {
+            &self.sources
+        }
+
+        pub fn sinks(&self) -> &[bool] {
+            &self.is_sink
+        }
+    }
+
+    fn site_index(side_size: usize, row: usize, col: usize) -> Result<usize, PercolationError> {
+        if row < 1 || row > side_size || col < 1 || col > side_size {
+            Err(PercolationError::OutOfBounds { row: row, col: col, side_size: side_size })
+        }
+        else {
+            Ok((row - 1) * side_size + col - 1)
+        }
+    }
+
+    // Without boundaries the sources are the top row and the sinks the bottom row.
+    pub struct PercolationBase {
+        states: SiteStates,
+        side_size: usize,
+        boundaries: Option<Boundaries>,
+        percolated: bool
+    }
+
+    impl PercolationBase {
+
+        pub fn new(side_size: usize) -> PercolationBase {
+            PercolationBase::from_states(side_size, SiteStates::new(side_size * side_size))
+        }
+
+        pub fn with_boundaries(side_size: usize, boundaries: Boundaries) -> PercolationBase {
+            PercolationBase {
+                states: SiteStates::new(side_size * side_size),
+                side_size: side_size,
+                boundaries: Some(boundaries),
+                percolated: false
+            }
+        }
+
+        // Top-bottom percolation has happened exactly when a site of the bottom row is full.
+        pub fn from_states(side_size: usize, states: SiteStates) -> PercolationBase {
+            let length = side_size * side_size;
+            let percolated = states.any_full_in(length - side_size, length);
+            PercolationBase {
+                states: states,
+                side_size: side_size,
+                boundaries: None,
+                percolated: percolated
+            }
+        }
+
+        pub fn boundaries(&self) -> Option<&Boundaries> {
+            self.boundaries.as_ref()
+        }
+
+        pub fn is_source(&self, index: usize) -> bool {
+            match self.boundaries {
+                Some(ref boundaries) => boundaries.is_source[index],
+                None => index < self.side_size,
+            }
+        }
+
+        pub fn is_sink(&self, index: usize) -> bool {
+            match self.boundaries {
+                Some(ref boundaries) => boundaries.is_sink[index],
+                None => !self.has_bottom_neighbor(index),
+            }
+        }
+
+        pub fn side_size(&self) -> usize {
+            self.side_size
+        }
+
+        pub fn states(&self) -> &SiteStates {
+            &self.states
+        }
+
+        pub fn percolated(&self) -> bool {
+            self.percolated
+        }
+
+        pub fn number_of_open_sites(&self) -> usize {
+            self.states.count_open()
+        }
+
+        pub fn close<L: PercolationListener>(&mut self, index: usize, listener: &mut L) {
+            let was_full = self.states.is_full(index);
+            self.states.make_blocked(index);
+            if !was_full {
+                return;
+            }
+            listener.on_drain(index / self.side_size + 1, index % self.side_size + 1);
+            // Only the full cluster around the closed site can lose its connection to the sources,
+            // so drain that cluster and flood it again from its sources.
+            let mut drained = Vec::new();
+            let mut stack = vec![index];
+            while let Some(index) = stack.pop() {
+                for neighbor in self.neighbors(index) {
+                    if self.states.is_full(neighbor) {
+                        self.states.make_open(neighbor);
+                        drained.push(neighbor);
+                        stack.push(neighbor);
+                    }
+                }
+            }
+            let length = self.side_size * self.side_size;
+            self.percolated = match self.boundaries {
+                Some(ref boundaries) => (0..length).any(|index| boundaries.is_sink[index] && self.states.is_full(index)),
+                None => self.states.any_full_in(length - self.side_size, length),
+            };
+            for &index in drained.iter() {
+                if self.is_source(index) && !self.states.is_full(index) {
+                    self.make_full(index, &mut NoListener);
+                    self.fill_neighbors(index, &mut NoListener);
+                }
+            }
+            for index in drained {
+                if !self.states.is_full(index) {
+                    listener.on_drain(index / self.side_size + 1, index % self.side_size + 1);
+                }
+            }
+        }
+
+        fn neighbors(&self, index: usize) -> Vec<usize> {
+            let mut neighbors = Vec::with_capacity(4);
+            if self.has_top_neighbor(index) {
+                neighbors.push(index - self.side_size);
+            }
+            if self.has_left_neighbor(index) {
+                neighbors.push(index - 1);
+            }
+            if self.has_right_neighbor(index) {
+                neighbors.push(index + 1);
+            }
+            if self.has_bottom_neighbor(index) {
+                neighbors.push(index + self.side_size);
+            }
+            neighbors
+        }
+    }
+
+    impl Ext for PercolationBase {
+
+        #[inline]
+        fn is_open_by_index(&self, index: usize) -> bool {
+            self.states.is_open(index)
+        }
+
+        #[inline]
+        fn is_full_by_index(&self, index: usize) -> bool {
+            self.states.is_full(index)
+        }
+
+        #[inline]
+        fn has_top_neighbor(&self, index: usize) -> bool {
+            index >= self.side_size
+        }
+
+        #[inline]
+        fn has_left_neighbor(&self, index: usize) -> bool {
+            index % self.side_size > 0
+        }
+
+        #[inline]
+        fn has_right_neighbor(&self, index: usize) -> bool {
+            index % self.side_size < self.side_size - 1
+        }
+
+        #[inline]
+        fn has_bottom_neighbor(&self, index: usize) -> bool {
+            index < self.side_size * (self.side_size - 1)
+        }
+
+        #[inline]
+        fn cell_index(&self, row: usize, col: usize) -> usize {
+            (row - 1) * self.side_size + col - 1
+        }
+
+        fn make_open(&mut self, index: usize) {
+            self.states.make_open(index);
+        }
+
+        fn make_full<L: PercolationListener>(&mut self, index: usize, listener: &mut L) {
+            if !self.states.is_full(index) {
+                self.states.make_full(index);
+                listener.on_full(index / self.side_size + 1, index % self.side_size + 1);
+                if self.is_sink(index) && !self.percolated {
+                    self.percolated = true;
+                    listener.on_percolate();
+                }
+            }
+        }
+
+        fn fill_towards<L: PercolationListener>(&mut self, index: usize, directions: Directions, listener: &mut L) {
+            let mut stack = vec![index];
+            while let Some(index) = stack.pop() {
+                if directions.up && self.has_top_neighbor(index) {
+                    let upper_index = index - self.side_size;
+                    if self.is_open_by_index(upper_index) && !self.is_full_by_index(upper_index) {
+                        self.make_full(upper_index, listener);
+                        stack.push(upper_index);
+                    }
+                }
+                if directions.right && self.has_right_neighbor(index) {
+                    let right_index = index + 1;
+                    if self.is_open_by_index(right_index) && !self.is_full_by_index(right_index) {
+                        self.make_full(right_index, listener);
+                        stack.push(right_index);
+                    }
+                }
+                if directions.left && self.has_left_neighbor(index) {
+                    let left_index = index - 1;
+                    if self.is_open_by_index(left_index) && !self.is_full_by_index(left_index) {
+                        self.make_full(left_index, listener);
+                        stack.push(left_index);
+                    }
+                }
+                if directions.down && self.has_bottom_neighbor(index) {
+                    let bottom_index = index + self.side_size;
+                    if self.is_open_by_index(bottom_index) && !self.is_full_by_index(bottom_index) {
+                        self.make_full(bottom_index, listener);
+                        stack.push(bottom_index);
+                    }
+                }
+            }
+        }
+    }
+
+}
+
+pub trait Percolation {
+
+    fn open(&mut self, row: usize, col: usize);
+
+    fn is_open(&self, row: usize, col: usize) -> bool;
+
+    fn is_full(&self, row: usize, col: usize) -> bool;
+
+    fn percolates(&self) -> bool;
+
+    fn side_size(&self) -> usize;
+
+    fn number_of_open_sites(&self) -> usize {
+        let side_size = self.side_size();
+        let mut open_sites = 0;
+        for row in 1..side_size + 1 {
+            for col in 1..side_size + 1 {
+                if self.is_open(row, col) {
+                    open_sites += 1;
+                }
+            }
+        }
+        open_sites
+    }
+
+    fn site(&self, row: usize, col: usize) -> Site {
+        if self.is_full(row, col) {
+            Site::Full
+        }
+        else if self.is_open(row, col) {
+            Site::Open
+        }
+        else {
+            Site::Blocked
+        }
+    }
+
+    fn percolating_path(&self) -> Option<Vec<(usize, usize)>> {
+        path::percolating_path(self)
+    }
+
+    fn backbone(&self) -> Vec<(usize, usize)> {
+        path::backbone(self)
+    }
+
+    fn check_site(&self, row: usize, col: usize) -> Result<(), PercolationError> {
+        let side_size = self.side_size();
+        if row < 1 || row > side_size || col < 1 || col > side_size {
+            Err(PercolationError::OutOfBounds { row: row, col: col, side_size: side_size })
+        }
+        else {
+            Ok(())
+        }
+    }
+
+    fn try_open(&mut self, row: usize, col: usize) -> Result<(), PercolationError> {
+        self.check_site(row, col)?;
+        self.open(row, col);
+        Ok(())
+    }
+
+    fn try_is_open(&self, row: usize, col: usize) -> Result<bool, PercolationError> {
+        self.check_site(row, col)?;
+        Ok(self.is_open(row, col))
+    }
+
+    fn try_is_full(&self, row: usize, col: usize) -> Result<bool, PercolationError> {
+        self.check_site(row, col)?;
+        Ok(self.is_full(row, col))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Site {
+    Blocked,
+    Open,
+    Full
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PercolationError {
+    EmptyGrid,
+    OutOfBounds { row: usize, col: usize, side_size: usize },
+    NoSuchVertex { vertex: usize, vertices: usize },
+    NoSuchEdge { edge: usize, edges: usize },
+    NotNeighbors { first: (usize, usize), second: (usize, usize) },
+    MaskMismatch { width: usize, height: usize, side_size: usize }
+}
+
+impl fmt::Display for PercolationError {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PercolationError::EmptyGrid => write!(f, "side size of a percolation grid must be positive"),
+            PercolationError::OutOfBounds { row, col, side_size } =>
+                write!(f, "site ({}, {}) is out of bounds, row and column must be between 1 and {}", row, col, side_size),
+            PercolationError::NoSuchVertex { vertex, vertices } =>
+                write!(f, "vertex {} does not exist, vertices must be in 0..{}", vertex, vertices),
+            PercolationError::NoSuchEdge { edge, edges } =>
+                write!(f, "edge {} does not exist, edges must be in 0..{}", edge, edges),
+            PercolationError::NotNeighbors { first, second } =>
+                write!(f, "sites ({}, {}) and ({}, {}) are not neighbors", first.0, first.1, second.0, second.1),
+            PercolationError::MaskMismatch { width, height, side_size } =>
+                write!(f, "mask of {}x{} sites does not fit grid of side size {}, the mask must be exactly as high \
+                    as the grid and at most as wide", width, height, side_size),
+        }
+    }
+}
+
+impl Error for PercolationError {
+
+    fn description(&self) -> &str {
+        match *self {
+            PercolationError::EmptyGrid => "empty percolation grid",
+            PercolationError::OutOfBounds { .. } => "site is out of bounds",
+            PercolationError::NoSuchVertex { .. } => "vertex does not exist",
+            PercolationError::NoSuchEdge { .. } => "edge does not exist",
+            PercolationError::NotNeighbors { .. } => "sites are not neighbors",
+            PercolationError::MaskMismatch { .. } => "mask does not fit grid",
+        }
+    }
+}
+
+fn check_side_size(side_size: usize) -> Result<(), PercolationError> {
+    if side_size == 0 {
+        Err(PercolationError::EmptyGrid)
+    }
+    else {
+        Ok(())
+    }
+}
+
+pub struct BruteForcePercolation<L = NoListener> {
+    base: PercolationBase,
+    listener: L
+}
+
+impl BruteForcePercolation {
+
+    pub fn new(side_size: usize) -> BruteForcePercolation {
+        BruteForcePercolation::with_listener(side_size, NoListener)
+    }
+
+    pub fn try_new(side_size: usize) -> Result<BruteForcePercolation, PercolationError> {
+        BruteForcePercolation::try_with_listener(side_size, NoListener)
+    }
+}
+
+impl<L: PercolationListener> BruteForcePercolation<L> {
+
+    pub fn with_listener(side_size: usize, listener: L) -> BruteForcePercolation<L> {
+        match BruteForcePercolation::try_with_listener(side_size, listener) {
+            Ok(percolation) => percolation,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_with_listener(side_size: usize, listener: L) -> Result<BruteForcePercolation<L>, PercolationError> {
+        check_side_size(side_size)?;
+        Ok(BruteForcePercolation {
+            base: PercolationBase::new(side_size),
+            listener: listener
+        })
+    }
+
+    pub fn listener(&self) -> &L {
+        &self.listener
+    }
+
+    pub fn listener_mut(&mut self) -> &mut L {
+        &mut self.listener
+    }
+
+    pub fn close(&mut self, row: usize, col: usize) {
+        if self.is_open(row, col) {
+            let index = self.base.cell_index(row, col);
+            self.listener.on_close(row, col);
+            self.base.close(index, &mut self.listener);
+        }
+    }
+
+    pub fn try_close(&mut self, row: usize, col: usize) -> Result<(), PercolationError> {
+        self.check_site(row, col)?;
+        self.close(row, col);
+        Ok(())
+    }
+}
+
+impl<L: PercolationListener> Percolation for BruteForcePercolation<L> {
+
+    fn open(&mut self, row: usize, col: usize) {
+        if !self.is_open(row, col) {
+            let index = self.base.cell_index(row, col);
+            self.base.make_open(index);
+            self.listener.on_open(row, col);
+            if self.base.has_top_neighbor(index) && self.is_full(row - 1, col)
+                    || self.base.has_bottom_neighbor(index) && self.is_full(row + 1, col) {
+                self.base.make_full(index, &mut self.listener);
+                self.base.fill_neighbors(index, &mut self.listener);
+            }
+            if self.base.has_left_neighbor(index) && self.is_full(row, col - 1)
+                    || self.base.has_right_neighbor(index) && self.is_full(row, col + 1){
+                self.base.make_full(index, &mut self.listener);
+                self.base.fill_neighbors(index, &mut self.listener);
+            }
+            if self.base.is_source(index) {
+                self.base.make_full(index, &mut self.listener);
+                self.base.fill_neighbors(index, &mut self.listener);
+            }
+        }
+    }
+
+    fn is_open(&self, row: usize, col: usize) -> bool {
+        let index = self.base.cell_index(row, col);
+        self.base.is_open_by_index(index)
+    }
+
+    fn is_full(&self, row: usize, col: usize) -> bool {
+        let index = self.base.cell_index(row, col);
+        self.base.is_full_by_index(index)
+    }
+
+    fn percolates(&self) -> bool {
+        self.base.percolated()
+    }
+
+    fn side_size(&self) -> usize {
+        self.base.side_size()
+    }
+
+    fn number_of_open_sites(&self) -> usize {
+        self.base.number_of_open_sites()
+    }
+}
+
+pub struct UnionFindPercolation<L = NoListener> {
+    union_find: CompactRankedQuickUnion,
+    virtual_cell: usize,
+    base: PercolationBase,
+    listener: L
+}
+
+impl UnionFindPercolation {
+
+    pub fn new(side_size: usize) -> UnionFindPercolation {
+        UnionFindPercolation::with_listener(side_size, NoListener)
+    }
+
+    pub fn try_new(side_size: usize) -> Result<UnionFindPercolation, PercolationError> {
+        UnionFindPercolation::try_with_listener(side_size, NoListener)
+    }
+
+    pub fn with_boundaries(side_size: usize, sources: &[(usize, usize)], sinks: &[(usize, usize)])
+            -> UnionFindPercolation {
+        match UnionFindPercolation::try_with_boundaries(side_size, sources, sinks) {
+            Ok(percolation) => percolation,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_with_boundaries(side_size: usize, sources: &[(usize, usize)], sinks: &[(usize, usize)])
+            -> Result<UnionFindPercolation, PercolationError> {
+        check_side_size(side_size)?;
+        let boundaries = Boundaries::new(side_size, sources, sinks)?;
+        Ok(UnionFindPercolation::from_base(PercolationBase::with_boundaries(side_size, boundaries), NoListener))
+    }
+}
+
+impl<L: PercolationListener> UnionFindPercolation<L> {
+
+    pub fn with_listener(side_size: usize, listener: L) -> UnionFindPercolation<L> {
+        match UnionFindPercolation::try_with_listener(side_size, listener) {
+            Ok(percolation) => percolation,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_with_listener(side_size: usize, listener: L) -> Result<UnionFindPercolation<L>, PercolationError> {
+        check_side_size(side_size)?;
+        Ok(UnionFindPercolation::from_base(PercolationBase::new(side_size), listener))
+    }
+
+    fn from_base(base: PercolationBase, listener: L) -> UnionFindPercolation<L> {
+        let length = base.side_size() * base.side_size();
+        let virtual_cell = length;
+        let mut union_find = CompactRankedQuickUnion::new(length + 1);
+        for p in (0..length).filter(|&p| base.is_source(p)) {
+            union_find.union(p, virtual_cell);
+        }
+        UnionFindPercolation {
+            union_find: union_find,
+            virtual_cell: virtual_cell,
+            base: base,
+            listener: listener
+        }
+    }
+
+    pub fn listener(&self) -> &L {
+        &self.listener
+    }
+
+    pub fn listener_mut(&mut self) -> &mut L {
+        &mut self.listener
+    }
+
+    fn connect_with_top(&mut self, index: usize) -> bool {
+        if self.base.has_top_neighbor(index) {
+            let upper_index = index - self.base.side_size();
+            let open = self.base.is_open_by_index(upper_index);
+            if open {
+                self.union_find.union(index, upper_index);
+            }
+            open
+        }
+        else {
+            false
+        }
+    }
+
+    fn connect_with_left(&mut self, index: usize) -> bool {
+        if self.base.has_left_neighbor(index) {
+            let left_index = index - 1;
+            let open = self.base.is_open_by_index(left_index);
+            if open {
+                self.union_find.union(index, left_index);
+            }
+            open
+        }
+        else {
+            false
+        }
+    }
+
+    fn connect_with_right(&mut self, index: usize) -> bool {
+        if self.base.has_right_neighbor(index) {
+            let right_index = index + 1;
+            let open = self.base.is_open_by_index(right_index);
+            if open {
+                self.union_find.union(index, right_index);
+            }
+            open
+        }
+        else {
+            false
+        }
+    }
+
+    fn connect_with_bottom(&mut self, index: usize) -> bool {
+        if self.base.has_bottom_neighbor(index) {
+            let bottom_index = index + self.base.side_size();
+            let open = self.base.is_open_by_index(bottom_index);
+            if open {
+                self.union_find.union(index, bottom_index);
+            }
+            open
+        }
+        else {
+            false
+        }
+    }
+}
+
+impl<L: PercolationListener> Percolation for UnionFindPercolation<L> {
+
+    fn open(&mut self, row: usize, col: usize) {
+        if !self.is_open(row, col) {
+            let index = self.base.cell_index(row, col);
+            self.base.make_open(index);
+            self.listener.on_open(row, col);
+            let union = self.connect_with_top(index)
+                | self.connect_with_left(index)
+                | self.connect_with_right(index)
+                | self.connect_with_bottom(index);
+            if (union || self.base.is_source(index)) && self.union_find.connected(index, self.virtual_cell) {
+                self.base.make_full(index, &mut self.listener);
+                self.base.fill_neighbors(index, &mut self.listener);
+            }
+        }
+    }
+
+    fn is_open(&self, row: usize, col: usize) -> bool {
+        let index = self.base.cell_index(row, col);
+        self.base.is_open_by_index(index)
+    }
+
+    fn is_full(&self, row: usize, col: usize) -> bool {
+        let index = self.base.cell_index(row, col);
+        self.base.is_full_by_index(index)
+    }
+
+    fn percolates(&self) -> bool {
+        self.base.percolated()
+    }
+
+    fn side_size(&self) -> usize {
+        self.base.side_size()
+    }
+
+    fn number_of_open_sites(&self) -> usize {
+        self.base.number_of_open_sites()
+    }
+
+    fn percolating_path(&self) -> Option<Vec<(usize, usize)>> {
+        match self.base.boundaries() {
+            Some(boundaries) => path::shortest_path(self, boundaries.sources(), boundaries.sinks()),
+            None => path::percolating_path(self),
+        }
+    }
+
+    fn backbone(&self) -> Vec<(usize, usize)> {
+        match self.base.boundaries() {
+            Some(boundaries) => path::flow_sites(self, boundaries.sources(), boundaries.sinks()),
+            None => path::backbone(self),
+        }
+    }
+}
+
+fn round_up_to_next_highest_power_of_two(mut v: usize) -> usize {
+    v -= 1;
+    v |= v >> 1;
+    v |= v >> 2;
+    v |= v >> 4;
+    v |= v >> 8;
+    v |= v >> 16;
+    v += 1;
+    v
+}
+
+pub struct HackUnionFindPercolation<L = NoListener> {
+    union_find: CompactRankedQuickUnion,
+    virtual_cell: usize,
+    states: SiteStates,
+    side_size: usize,
+    lg_2: usize,
+    mask: usize,
+    percolated: bool,
+    listener: L
+}
+
+impl HackUnionFindPercolation {
+
+    pub fn new(side_size: usize) -> HackUnionFindPercolation {
+        HackUnionFindPercolation::with_listener(side_size, NoListener)
+    }
+
+    pub fn try_new(side_size: usize) -> Result<HackUnionFindPercolation, PercolationError> {
+        HackUnionFindPercolation::try_with_listener(side_size, NoListener)
+    }
+}
+
+impl<L: PercolationListener> HackUnionFindPercolation<L> {
+
+    pub fn with_listener(side_size: usize, listener: L) -> HackUnionFindPercolation<L> {
+        match HackUnionFindPercolation::try_with_listener(side_size, listener) {
+            Ok(percolation) => percolation,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_with_listener(side_size: usize, listener: L) -> Result<HackUnionFindPercolation<L>, PercolationError> {
+        check_side_size(side_size)?;
+        let power_of_two = round_up_to_next_highest_power_of_two(side_size);
+        let virtual_cell = side_size * power_of_two;
+        let lg_2 = (power_of_two as f64).log2() as usize;
+        let mut union_find = CompactRankedQuickUnion::new(virtual_cell + 1);
+        for p in 0..side_size {
+            union_find.union(p, virtual_cell);
+        }
+        Ok(HackUnionFindPercolation {
+            union_find: union_find,
+            virtual_cell: virtual_cell,
+            states: SiteStates::new(side_size << lg_2),
+            side_size: side_size,
+            lg_2: lg_2,
+            mask: power_of_two - 1,
+            percolated: false,
+            listener: listener
+        })
+    }
+
+    pub fn listener(&self) -> &L {
+        &self.listener
+    }
+
+    pub fn listener_mut(&mut self) -> &mut L {
+        &mut self.listener
+    }
+
+    fn connect_with_top(&mut self, index: usize) -> bool {
+        if self.has_top_neighbor(index) {
+            let diff = index & self.mask;
+            let upper_index = (((index >> self.lg_2) - 1) << self.lg_2) | diff;
+            let open = self.is_open_by_index(upper_index);
+            if open {
+                self.union_find.union(index, upper_index);
+            }
+            open
+        }
+        else {
+            false
+        }
+    }
+
+    fn connect_with_left(&mut self, index: usize) -> bool {
+        if self.has_left_neighbor(index) {
+            let left_index = index - 1;
+            let open = self.is_open_by_index(left_index);
+            if open {
+                self.union_find.union(index, left_index);
+            }
+            open
+        }
+        else {
+            false
+        }
+    }
+
+    fn connect_with_right(&mut self, index: usize) -> bool {
+        if self.has_right_neighbor(index) {
+            let right_index = index + 1;
+            let open = self.is_open_by_index(right_index);
+            if open {
+                self.union_find.union(index, right_index);
+            }
+            open
+        }
+        else {
+            false
+        }
+    }
+
+    fn connect_with_bottom(&mut self, index: usize) -> bool {
+        if self.has_bottom_neighbor(index) {
+            let diff = index & self.mask;
+            let bottom_index = (((index >> self.lg_2) + 1) << self.lg_2) | diff;
+            let open = self.is_open_by_index(bottom_index);
+            if open {
+                self.union_find.union(index, bottom_index);
+            }
+            open
+        }
+        else {
+            false
+        }
+    }
+
+    #[inline]
+    fn cell_index(&self, row: usize, col: usize) -> usize {
+        ((row - 1) << self.lg_2) + col - 1
+    }
+
+    #[inline]
+    fn is_open_by_index(&self, index: usize) -> bool {
+        self.states.is_open(index)
+    }
+
+    #[inline]
+    fn is_full_by_index(&self, index: usize) -> bool {
+        self.states.is_full(index)
+    }
+
+    #[inline]
+    fn has_top_neighbor(&self, index: usize) -> bool {
+        index >= self.side_size
+    }
+
+    #[inline]
+    fn has_left_neighbor(&self, index: usize) -> bool {
+        index & self.mask > 0
+    }
+
+    #[inline]
+    fn has_right_neighbor(&self, index: usize) -> bool {
+        index & self.mask < self.side_size - 1
+    }
+
+    #[inline]
+    fn has_bottom_neighbor(&self, index: usize) -> bool {
+        index >> self.lg_2 != self.side_size - 1
+    }
+
+    fn make_full(&mut self, index: usize) {
+        self.states.make_full(index);
+        self.listener.on_full((index >> self.lg_2) + 1, (index & self.mask) + 1);
+        if !self.has_bottom_neighbor(index) && !self.percolated {
+            self.percolated = true;
+            self.listener.on_percolate();
+        }
+    }
+
+    fn fill_neighbors(&mut self, index: usize) {
+        let mut stack = vec![index];
+        while let Some(index) = stack.pop() {
+            if self.has_top_neighbor(index) {
+                let diff = index & self.mask;
+                let upper_index = (((index >> self.lg_2) - 1) << self.lg_2) | diff;
+                if self.is_open_by_index(upper_index) && !self.is_full_by_index(upper_index) {
+                    self.make_full(upper_index);
+                    stack.push(upper_index);
+                }
+            }
+            if self.has_right_neighbor(index) {
+                let right_index = index + 1;
+                if self.is_open_by_index(right_index) && !self.is_full_by_index(right_index) {
+                    self.make_full(right_index);
+                    stack.push(right_index);
+                }
+            }
+            if self.has_left_neighbor(index) {
+                let left_index = index - 1;
+                if self.is_open_by_index(left_index) && !self.is_full_by_index(left_index) {
+                    self.make_full(left_index);
+                    stack.push(left_index);
+                }
+            }
+            if self.has_bottom_neighbor(index) {
+                let diff = index & self.mask;
+                let bottom_index = (((index >> self.lg_2) + 1) << self.lg_2) | diff;
+                if self.is_open_by_index(bottom_index) && !self.is_full_by_index(bottom_index) {
+                    self.make_full(bottom_index);
+                    stack.push(bottom_index);
+                }
+            }
+        }
+    }
+}
+
+impl<L: PercolationListener> Percolation for HackUnionFindPercolation<L> {
+
+    fn open(&mut self, row: usize, col: usize) {
+        if !self.is_open(row, col) {
+            let index = self.cell_index(row, col);
+            self.states.make_open(index);
+            self.listener.on_open(row, col);
+            let union = self.connect_with_top(index)
+                | self.connect_with_left(index)
+                | self.connect_with_right(index)
+                | self.connect_with_bottom(index);
+            if (union || row == 1) && self.union_find.connected(index, self.virtual_cell) {
+                self.make_full(index);
+                self.fill_neighbors(index);
+            }
+        }
+    }
+
+    fn is_open(&self, row: usize, col: usize) -> bool {
+        let index = self.cell_index(row, col);
+        self.is_open_by_index(index)
+    }
+
+    fn is_full(&self, row: usize, col: usize) -> bool {
+        let index = self.cell_index(row, col);
+        self.is_full_by_index(index)
+    }
+
+    fn percolates(&self) -> bool {
+        self.percolated
+    }
+
+    fn side_size(&self) -> usize {
+        self.side_size
+    }
+
+    fn number_of_open_sites(&self) -> usize {
+        self.states.count_open()
+    }
+}
@@ -0,0 +1,203 @@
+use rand::Rng;
+
+use union_find::{UnionFind, PathCompressionWeightedQuickUnion};
+
+use super::{PercolationError, check_side_size};
+use super::stats::random_order;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CurvePoint {
+    pub p: f64,
+    pub percolation_probability: f64,
+    pub largest_cluster_fraction: f64
+}
+
+pub struct NewmanZiff {
+    side_size: usize,
+    trials: usize,
+    spanning: Vec<f64>,
+    largest_cluster: Vec<f64>
+}
+
+impl NewmanZiff {
+
+    pub fn run<R: Rng>(side_size: usize, trials: usize, rng: &mut R) -> NewmanZiff {
+        match NewmanZiff::try_run(side_size, trials, rng) {
+            Ok(newman_ziff) => newman_ziff,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_run<R: Rng>(side_size: usize, trials: usize, rng: &mut R) -> Result<NewmanZiff, PercolationError> {
+        check_side_size(side_size)?;
+        let length = side_size * side_size;
+        let orders = (0..trials).map(|_| random_order(length, rng)).collect::<Vec<_>>();
+        NewmanZiff::try_from_orders(side_size, orders)
+    }
+
+    pub fn from_orders<I: IntoIterator<Item = Vec<usize>>>(side_size: usize, orders: I) -> NewmanZiff {
+        match NewmanZiff::try_from_orders(side_size, orders) {
+            Ok(newman_ziff) => newman_ziff,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_from_orders<I: IntoIterator<Item = Vec<usize>>>(side_size: usize, orders: I)
+        -> Result<NewmanZiff, PercolationError> {
+        check_side_size(side_size)?;
+        let length = side_size * side_size;
+        let mut spanning = vec![0.0; length + 1];
+        let mut largest_cluster = vec![0.0; length + 1];
+        let mut trials = 0;
+        for order in orders {
+            assert_eq!(order.len(), length, "every site must be opened exactly once");
+            let (trial_spanning, trial_largest_cluster) = single_pass(side_size, &order);
+            for n in 0..length + 1 {
+                spanning[n] += trial_spanning[n];
+                largest_cluster[n] += trial_largest_cluster[n];
+            }
+            trials += 1;
+        }
+        let scale = if trials == 0 { 0.0 } else { 1.0 / trials as f64 };
+        for n in 0..length + 1 {
+            spanning[n] *= scale;
+            largest_cluster[n] *= scale / length as f64;
+        }
+        Ok(NewmanZiff {
+            side_size: side_size,
+            trials: trials,
+            spanning: spanning,
+            largest_cluster: largest_cluster
+        })
+    }
+
+    pub fn side_size(&self) -> usize {
+        self.side_size
+    }
+
+    pub fn trials(&self) -> usize {
+        self.trials
+    }
+
+    pub fn spanning_by_open_sites(&self) -> &[f64] {
+        &self.spanning
+    }
+
+    pub fn largest_cluster_by_open_sites(&self) -> &[f64] {
+        &self.largest_cluster
+    }
+
+    pub fn percolation_probability(&self, p: f64) -> f64 {
+        convolve(&self.spanning, p)
+    }
+
+    pub fn largest_cluster_fraction(&self, p: f64) -> f64 {
+        convolve(&self.largest_cluster, p)
+    }
+
+    // Without trials there is nothing to average, so the curve has no points.
+    pub fn curve(&self, ps: &[f64]) -> Vec<CurvePoint> {
+        if self.trials == 0 {
+            return Vec::new();
+        }
+        ps.iter()
+            .map(|&p| {
+                let weights = binomial_weights(self.spanning.len() - 1, p);
+                CurvePoint {
+                    p: p,
+                    percolation_probability: weighted_sum(&weights, &self.spanning),
+                    largest_cluster_fraction: weighted_sum(&weights, &self.largest_cluster)
+                }
+            })
+            .collect()
+    }
+}
+
+// Virtual top and bottom cells would count towards the size of the clusters they join and
+// merge every cluster touching the same row, so boundaries are flags of the cluster roots instead.
+fn single_pass(side_size: usize, order: &[usize]) -> (Vec<f64>, Vec<f64>) {
+    let length = side_size * side_size;
+    let mut union_find = PathCompressionWeightedQuickUnion::new(length);
+    let mut openness = vec![false; length];
+    let mut touches_top = (0..length).map(|index| index < side_size).collect::<Vec<_>>();
+    let mut touches_bottom = (0..length).map(|index| index >= length - side_size).collect::<Vec<_>>();
+    let mut spanning = Vec::with_capacity(length + 1);
+    let mut largest_cluster = Vec::with_capacity(length + 1);
+    spanning.push(0.0);
+    largest_cluster.push(0.0);
+    let mut largest = 0;
+    let mut spans = false;
+    for &index in order {
+        openness[index] = true;
+        let row = index / side_size;
+        let col = index % side_size;
+        let mut neighbors = Vec::with_capacity(4);
+        if row > 0 && openness[index - side_size] {
+            neighbors.push(index - side_size);
+        }
+        if row < side_size - 1 && openness[index + side_size] {
+            neighbors.push(index + side_size);
+        }
+        if col > 0 && openness[index - 1] {
+            neighbors.push(index - 1);
+        }
+        if col < side_size - 1 && openness[index + 1] {
+            neighbors.push(index + 1);
+        }
+        for neighbor in neighbors {
+            let index_root = union_find.find(index);
+            let neighbor_root = union_find.find(neighbor);
+            union_find.union(index, neighbor);
+            let root = union_find.find(index);
+            touches_top[root] = touches_top[index_root] || touches_top[neighbor_root];
+            touches_bottom[root] = touches_bottom[index_root] || touches_bottom[neighbor_root];
+        }
+        let root = union_find.find(index);
+        let size = union_find.size(index);
+        if size > largest {
+            largest = size;
+        }
+        spans = spans || (touches_top[root] && touches_bottom[root]);
+        spanning.push(if spans { 1.0 } else { 0.0 });
+        largest_cluster.push(largest as f64);
+    }
+    (spanning, largest_cluster)
+}
+
+fn convolve(observable: &[f64], p: f64) -> f64 {
+    let weights = binomial_weights(observable.len() - 1, p);
+    weighted_sum(&weights, observable)
+}
+
+fn weighted_sum(weights: &[f64], observable: &[f64]) -> f64 {
+    weights.iter().zip(observable.iter()).fold(0.0, |sum, (w, o)| sum + w * o)
+}
+
+pub fn binomial_weights(n: usize, p: f64) -> Vec<f64> {
+    let mut weights = vec![0.0; n + 1];
+    if p <= 0.0 {
+        weights[0] = 1.0;
+        return weights;
+    }
+    if p >= 1.0 {
+        weights[n] = 1.0;
+        return weights;
+    }
+    let log_ratio = (p / (1.0 - p)).ln();
+    let mut log_weights = Vec::with_capacity(n + 1);
+    log_weights.push(n as f64 * (1.0 - p).ln());
+    for k in 1..n + 1 {
+        let previous = log_weights[k - 1];
+        log_weights.push(previous + ((n - k + 1) as f64 / k as f64).ln() + log_ratio);
+    }
+    let max = log_weights.iter().cloned().fold(::std::f64::NEG_INFINITY, f64::max);
+    let mut total = 0.0;
+    for k in 0..n + 1 {
+        weights[k] = (log_weights[k] - max).exp();
+        total += weights[k];
+    }
+    for weight in weights.iter_mut() {
+        *weight /= total;
+    }
+    weights
+}
@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+
+use super::Percolation;
+
+const UNVISITED: usize = ::std::usize::MAX;
+
+fn open_neighbors<P: Percolation + ?Sized>(percolation: &P, index: usize) -> Vec<usize> {
+    let side_size = percolation.side_size();
+    let row = index / side_size + 1;
+    let col = index % side_size + 1;
+    let mut neighbors = Vec::with_capacity(4);
+    if row > 1 && percolation.is_open(row - 1, col) {
+        neighbors.push(index - side_size);
+    }
+    if col > 1 && percolation.is_open(row, col - 1) {
+        neighbors.push(index - 1);
+    }
+    if col < side_size && percolation.is_open(row, col + 1) {
+        neighbors.push(index + 1);
+    }
+    if row < side_size && percolation.is_open(row + 1, col) {
+        neighbors.push(index + side_size);
+    }
+    neighbors
+}
+
+fn site(side_size: usize, index: usize) -> (usize, usize) {
+    (index / side_size + 1, index % side_size + 1)
+}
+
+fn top_and_bottom(side_size: usize) -> (Vec<usize>, Vec<bool>) {
+    let length = side_size * side_size;
+    let sources = (0..side_size).collect();
+    let sinks = (0..length).map(|index| index >= length - side_size).collect();
+    (sources, sinks)
+}
+
+pub fn percolating_path<P: Percolation + ?Sized>(percolation: &P) -> Option<Vec<(usize, usize)>> {
+    let (sources, sinks) = top_and_bottom(percolation.side_size());
+    shortest_path(percolation, &sources, &sinks)
+}
+
+pub fn backbone<P: Percolation + ?Sized>(percolation: &P) -> Vec<(usize, usize)> {
+    let (sources, sinks) = top_and_bottom(percolation.side_size());
+    flow_sites(percolation, &sources, &sinks)
+}
+
+pub fn shortest_path<P>(percolation: &P, sources: &[usize], sinks: &[bool]) -> Option<Vec<(usize, usize)>>
+        where P: Percolation + ?Sized {
+    let side_size = percolation.side_size();
+    let length = side_size * side_size;
+    let mut previous = vec![UNVISITED; length];
+    let mut queue = VecDeque::new();
+    for &index in sources {
+        if percolation.is_open(index / side_size + 1, index % side_size + 1) && previous[index] == UNVISITED {
+            previous[index] = index;
+            queue.push_back(index);
+        }
+    }
+    while let Some(index) = queue.pop_front() {
+        if sinks[index] {
+            let mut path = vec![site(side_size, index)];
+            let mut current = index;
+            while previous[current] != current {
+                current = previous[current];
+                path.push(site(side_size, current));
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for neighbor in open_neighbors(percolation, index) {
+            if previous[neighbor] == UNVISITED {
+                previous[neighbor] = index;
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    None
+}
+
+pub fn flow_sites<P: Percolation + ?Sized>(percolation: &P, sources: &[usize], sinks: &[bool]) -> Vec<(usize, usize)> {
+    let side_size = percolation.side_size();
+    let length = side_size * side_size;
+    let source = length;
+    let sink = length + 1;
+    let is_open = |index: usize| percolation.is_open(index / side_size + 1, index % side_size + 1);
+    let mut is_source = vec![false; length];
+    for &index in sources {
+        is_source[index] = true;
+    }
+    let neighbors = |vertex: usize| -> Vec<usize> {
+        if vertex == source {
+            let mut neighbors = (0..length).filter(|&index| is_source[index] && is_open(index)).collect::<Vec<_>>();
+            neighbors.push(sink);
+            neighbors
+        }
+        else if vertex == sink {
+            let mut neighbors = (0..length).filter(|&index| sinks[index] && is_open(index)).collect::<Vec<_>>();
+            neighbors.push(source);
+            neighbors
+        }
+        else {
+            let mut neighbors = open_neighbors(percolation, vertex);
+            if is_source[vertex] {
+                neighbors.push(source);
+            }
+            if sinks[vertex] {
+                neighbors.push(sink);
+            }
+            neighbors
+        }
+    };
+
+    // A site carries flow iff it lies on a simple source-sink path, that is iff it belongs to
+    // the biconnected component of the extra source-sink edge.
+    let mut discovery = vec![0; length + 2];
+    let mut low = vec![0; length + 2];
+    let mut parent = vec![UNVISITED; length + 2];
+    let mut edges = Vec::new();
+    let mut frames = vec![(source, neighbors(source), 0)];
+    let mut time = 1;
+    discovery[source] = time;
+    low[source] = time;
+    while !frames.is_empty() {
+        let top = frames.len() - 1;
+        let vertex = frames[top].0;
+        if frames[top].2 < frames[top].1.len() {
+            let next = frames[top].1[frames[top].2];
+            frames[top].2 += 1;
+            if discovery[next] == 0 {
+                time += 1;
+                discovery[next] = time;
+                low[next] = time;
+                parent[next] = vertex;
+                edges.push((vertex, next));
+                frames.push((next, neighbors(next), 0));
+            }
+            else if next != parent[vertex] && discovery[next] < discovery[vertex] {
+                edges.push((vertex, next));
+                if discovery[next] < low[vertex] {
+                    low[vertex] = discovery[next];
+                }
+            }
+        }
+        else {
+            frames.pop();
+            let child = vertex;
+            if let Some(&(vertex, _, _)) = frames.last() {
+                if low[child] < low[vertex] {
+                    low[vertex] = low[child];
+                }
+                if low[child] >= discovery[vertex] {
+                    let mut component = Vec::new();
+                    let mut flows = false;
+                    while let Some((p, q)) = edges.pop() {
+                        flows |= (p == source && q == sink) || (p == sink && q == source);
+                        component.push(p);
+                        component.push(q);
+                        if p == vertex && q == child {
+                            break;
+                        }
+                    }
+                    if flows {
+                        component.retain(|&v| v < length);
+                        component.sort();
+                        component.dedup();
+                        return component.into_iter().map(|index| site(side_size, index)).collect();
+                    }
+                }
+            }
+        }
+    }
+    Vec::new()
+}
@@ -0,0 +1,107 @@
+use rand::Rng;
+
+use union_find::{UnionFind, WeightedQuickUnion};
+
+pub struct RandomGraphTrial {
+    largest_components: Vec<usize>,
+    giant_component_at: usize,
+    connected_at: usize
+}
+
+impl RandomGraphTrial {
+
+    pub fn run<R: Rng>(vertices: usize, giant_fraction: f64, rng: &mut R) -> RandomGraphTrial {
+        let giant_size = (giant_fraction * vertices as f64).ceil() as usize;
+        let mut union_find = WeightedQuickUnion::new(vertices);
+        let mut components = vertices;
+        let mut largest = if vertices == 0 { 0 } else { 1 };
+        let mut largest_components = vec![largest];
+        let mut giant_component_at = if largest >= giant_size { Some(0) } else { None };
+        let mut edges = 0;
+        while components > 1 {
+            let p = rng.gen_range(0, vertices);
+            let q = rng.gen_range(0, vertices);
+            if p == q {
+                continue;
+            }
+            edges += 1;
+            if !union_find.connected(p, q) {
+                union_find.union(p, q);
+                components -= 1;
+                let size = union_find.size(p);
+                if size > largest {
+                    largest = size;
+                }
+            }
+            largest_components.push(largest);
+            if giant_component_at.is_none() && largest >= giant_size {
+                giant_component_at = Some(edges);
+            }
+        }
+        RandomGraphTrial {
+            largest_components: largest_components,
+            giant_component_at: giant_component_at.unwrap_or(edges),
+            connected_at: edges
+        }
+    }
+
+    pub fn largest_component_by_edges(&self) -> &[usize] {
+        &self.largest_components
+    }
+
+    pub fn giant_component_at(&self) -> usize {
+        self.giant_component_at
+    }
+
+    pub fn connected_at(&self) -> usize {
+        self.connected_at
+    }
+}
+
+pub struct RandomGraph {
+    vertices: usize,
+    trials: Vec<RandomGraphTrial>
+}
+
+impl RandomGraph {
+
+    pub fn run<R: Rng>(vertices: usize, trials: usize, giant_fraction: f64, rng: &mut R) -> RandomGraph {
+        RandomGraph {
+            vertices: vertices,
+            trials: (0..trials).map(|_| RandomGraphTrial::run(vertices, giant_fraction, rng)).collect()
+        }
+    }
+
+    pub fn vertices(&self) -> usize {
+        self.vertices
+    }
+
+    pub fn trials(&self) -> &[RandomGraphTrial] {
+        &self.trials
+    }
+
+    pub fn mean_giant_component_at(&self) -> f64 {
+        self.mean(|trial| trial.giant_component_at)
+    }
+
+    pub fn mean_connected_at(&self) -> f64 {
+        self.mean(|trial| trial.connected_at)
+    }
+
+    pub fn largest_component_by_edges(&self) -> Vec<f64> {
+        let edges = self.trials.iter().map(|trial| trial.connected_at).max().unwrap_or(0);
+        (0..edges + 1)
+            .map(|edge| self.mean(|trial| {
+                let largest = &trial.largest_components;
+                if edge < largest.len() { largest[edge] } else { self.vertices }
+            }))
+            .collect()
+    }
+
+    fn mean<F: Fn(&RandomGraphTrial) -> usize>(&self, value: F) -> f64 {
+        if self.trials.is_empty() {
+            return 0.0;
+        }
+        self.trials.iter().fold(0.0, |sum, trial| sum + value(trial) as f64) / self.trials.len() as f64
+    }
+}
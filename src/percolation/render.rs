@@ -0,0 +1,135 @@
+use std::io::{self, Write};
+
+use super::{Percolation, Site};
+
+// Princeton's PercolationVisualizer palette: StdDraw.BLACK, StdDraw.WHITE and StdDraw.BOOK_LIGHT_BLUE.
+const BLOCKED_RGB: [u8; 3] = [0, 0, 0];
+const OPEN_RGB: [u8; 3] = [255, 255, 255];
+const FULL_RGB: [u8; 3] = [103, 198, 243];
+const HIGHLIGHT_RGB: [u8; 3] = [255, 196, 0];
+
+const ANSI_RESET: &'static str = "\x1b[0m";
+
+const BLOCKED_GRAY: u8 = 0;
+const OPEN_GRAY: u8 = 255;
+const FULL_GRAY: u8 = 128;
+
+fn ascii(site: Site) -> char {
+    match site {
+        Site::Blocked => '#',
+        Site::Open => '.',
+        Site::Full => '~',
+    }
+}
+
+fn rgb(site: Site) -> [u8; 3] {
+    match site {
+        Site::Blocked => BLOCKED_RGB,
+        Site::Open => OPEN_RGB,
+        Site::Full => FULL_RGB,
+    }
+}
+
+fn gray(site: Site) -> u8 {
+    match site {
+        Site::Blocked => BLOCKED_GRAY,
+        Site::Open => OPEN_GRAY,
+        Site::Full => FULL_GRAY,
+    }
+}
+
+fn hex(color: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", color[0], color[1], color[2])
+}
+
+pub fn to_ascii<P: Percolation + ?Sized>(percolation: &P) -> String {
+    let side_size = percolation.side_size();
+    let mut ascii_art = String::with_capacity((side_size + 1) * side_size);
+    for row in 1..side_size + 1 {
+        for col in 1..side_size + 1 {
+            ascii_art.push(ascii(percolation.site(row, col)));
+        }
+        ascii_art.push('\n');
+    }
+    ascii_art
+}
+
+pub fn to_ansi<P: Percolation + ?Sized>(percolation: &P, highlighted: &[(usize, usize)]) -> String {
+    let side_size = percolation.side_size();
+    let mut is_highlighted = vec![false; side_size * side_size];
+    for &(row, col) in highlighted {
+        is_highlighted[(row - 1) * side_size + col - 1] = true;
+    }
+    let mut ansi_art = String::new();
+    for row in 1..side_size + 1 {
+        let mut previous = None;
+        for col in 1..side_size + 1 {
+            let color = if is_highlighted[(row - 1) * side_size + col - 1] {
+                HIGHLIGHT_RGB
+            }
+            else {
+                rgb(percolation.site(row, col))
+            };
+            if previous != Some(color) {
+                ansi_art.push_str(&format!("\x1b[48;2;{};{};{}m", color[0], color[1], color[2]));
+                previous = Some(color);
+            }
+            ansi_art.push_str("  ");
+        }
+        ansi_art.push_str(ANSI_RESET);
+        ansi_art.push('\n');
+    }
+    ansi_art
+}
+
+pub fn write_ppm<P: Percolation + ?Sized, W: Write>(percolation: &P, cell_size: usize, writer: &mut W) -> io::Result<()> {
+    let pixels = percolation.side_size() * cell_size;
+    write!(writer, "P6\n{} {}\n255\n", pixels, pixels)?;
+    write_raster(percolation, cell_size, writer, |site| rgb(site).to_vec())
+}
+
+pub fn write_pgm<P: Percolation + ?Sized, W: Write>(percolation: &P, cell_size: usize, writer: &mut W) -> io::Result<()> {
+    let pixels = percolation.side_size() * cell_size;
+    write!(writer, "P5\n{} {}\n255\n", pixels, pixels)?;
+    write_raster(percolation, cell_size, writer, |site| vec![gray(site)])
+}
+
+fn write_raster<P, W, F>(percolation: &P, cell_size: usize, writer: &mut W, color: F) -> io::Result<()>
+        where P: Percolation + ?Sized, W: Write, F: Fn(Site) -> Vec<u8> {
+    let side_size = percolation.side_size();
+    for row in 1..side_size + 1 {
+        let mut line = Vec::new();
+        for col in 1..side_size + 1 {
+            let pixel = color(percolation.site(row, col));
+            for _ in 0..cell_size {
+                line.extend_from_slice(&pixel);
+            }
+        }
+        for _ in 0..cell_size {
+            writer.write_all(&line)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn to_svg<P: Percolation + ?Sized>(percolation: &P, cell_size: usize) -> String {
+    let side_size = percolation.side_size();
+    let pixels = side_size * cell_size;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{0}\" viewBox=\"0 0 {0} {0}\">\n",
+        pixels
+    );
+    for row in 1..side_size + 1 {
+        for col in 1..side_size + 1 {
+            svg.push_str(&format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{2}\" height=\"{2}\" fill=\"{3}\"/>\n",
+                (col - 1) * cell_size,
+                (row - 1) * cell_size,
+                cell_size,
+                hex(rgb(percolation.site(row, col)))
+            ));
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
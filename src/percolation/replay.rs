@@ -0,0 +1,87 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use number_reader;
+
+use super::{Percolation, PercolationError};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Step {
+    pub row: usize,
+    pub col: usize,
+    pub open_sites: usize,
+    pub percolates: bool
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Replay {
+    percolated_at: Option<usize>,
+    open_sites: Option<usize>,
+    steps: usize,
+    trace: Option<Vec<Step>>
+}
+
+impl Replay {
+
+    pub fn percolated_at(&self) -> Option<usize> {
+        self.percolated_at
+    }
+
+    pub fn open_sites(&self) -> Option<usize> {
+        self.open_sites
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn trace(&self) -> Option<&[Step]> {
+        self.trace.as_ref().map(|trace| &trace[..])
+    }
+}
+
+pub fn replay<P, I>(percolation: &mut P, sites: I, trace: bool) -> Result<Replay, PercolationError>
+        where P: Percolation + ?Sized, I: IntoIterator<Item = (usize, usize)> {
+    let mut replay = Replay {
+        percolated_at: None,
+        open_sites: None,
+        steps: 0,
+        trace: if trace { Some(Vec::new()) } else { None }
+    };
+    let mut open_sites = percolation.number_of_open_sites();
+    let mut percolates = percolation.percolates();
+    for (step, (row, col)) in sites.into_iter().enumerate() {
+        if !percolation.try_is_open(row, col)? {
+            percolation.try_open(row, col)?;
+            open_sites += 1;
+        }
+        if !percolates && percolation.percolates() {
+            percolates = true;
+            replay.percolated_at = Some(step);
+            replay.open_sites = Some(open_sites);
+        }
+        if let Some(ref mut trace) = replay.trace {
+            trace.push(Step { row: row, col: col, open_sites: open_sites, percolates: percolates });
+        }
+        replay.steps = step + 1;
+    }
+    Ok(replay)
+}
+
+pub fn replay_file<P, F, Q>(path: Q, new: F, trace: bool) -> io::Result<(P, Replay)>
+        where P: Percolation, F: FnOnce(usize) -> Result<P, PercolationError>, Q: AsRef<Path> {
+    let file = File::open(path)?;
+    let (side_size, sites) = read_sites(BufReader::new(file))?;
+    let mut percolation = new(side_size).map_err(invalid_sites)?;
+    let replay = replay(&mut percolation, sites, trace).map_err(invalid_sites)?;
+    Ok((percolation, replay))
+}
+
+fn invalid_sites(e: PercolationError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+pub fn read_sites<R: BufRead>(reader: R) -> io::Result<(usize, Vec<(usize, usize)>)> {
+    number_reader::read_pairs(reader, "side size", "row", "column")
+}
@@ -0,0 +1,146 @@
+use super::Site;
+
+const BITS_PER_SITE: usize = 2;
+const SITES_PER_WORD: usize = 64 / BITS_PER_SITE;
+const OPEN_BITS: u64 = 0x5555_5555_5555_5555;
+const FULL_BITS: u64 = 0xaaaa_aaaa_aaaa_aaaa;
+const OPEN: u64 = 0b01;
+const FULL: u64 = 0b11;
+
+pub struct SiteStates {
+    words: Vec<u64>,
+    length: usize
+}
+
+impl SiteStates {
+
+    pub fn new(length: usize) -> SiteStates {
+        SiteStates {
+            words: vec![0; SiteStates::word_count(length)],
+            length: length
+        }
+    }
+
+    // Full bits without open bits and open sites past the length would not be a state of any grid.
+    pub fn from_words(length: usize, words: Vec<u64>) -> Option<SiteStates> {
+        if words.len() != SiteStates::word_count(length) {
+            return None;
+        }
+        let full_without_open = words.iter().any(|&word| word & FULL_BITS & !(word << 1) != 0);
+        let tail = length % SITES_PER_WORD;
+        let padding_used = tail != 0 && words[words.len() - 1] >> (tail * BITS_PER_SITE) != 0;
+        if full_without_open || padding_used {
+            None
+        }
+        else {
+            Some(SiteStates {
+                words: words,
+                length: length
+            })
+        }
+    }
+
+    pub fn word_count(length: usize) -> usize {
+        length / SITES_PER_WORD + if length % SITES_PER_WORD == 0 { 0 } else { 1 }
+    }
+
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Site {
+        match self.bits(index) {
+            0 => Site::Blocked,
+            OPEN => Site::Open,
+            _ => Site::Full,
+        }
+    }
+
+    #[inline]
+    pub fn is_open(&self, index: usize) -> bool {
+        self.bits(index) & OPEN != 0
+    }
+
+    #[inline]
+    pub fn is_full(&self, index: usize) -> bool {
+        self.bits(index) == FULL
+    }
+
+    #[inline]
+    pub fn make_blocked(&mut self, index: usize) {
+        self.set(index, 0);
+    }
+
+    #[inline]
+    pub fn make_open(&mut self, index: usize) {
+        self.set(index, OPEN);
+    }
+
+    #[inline]
+    pub fn make_full(&mut self, index: usize) {
+        self.set(index, FULL);
+    }
+
+    pub fn count_open(&self) -> usize {
+        self.count(0, self.length, OPEN_BITS)
+    }
+
+    pub fn count_full(&self) -> usize {
+        self.count(0, self.length, FULL_BITS)
+    }
+
+    pub fn count_open_in(&self, from: usize, to: usize) -> usize {
+        self.count(from, to, OPEN_BITS)
+    }
+
+    pub fn any_full_in(&self, from: usize, to: usize) -> bool {
+        self.count(from, to, FULL_BITS) > 0
+    }
+
+    #[inline]
+    fn bits(&self, index: usize) -> u64 {
+        debug_assert!(index < self.length);
+        let shift = (index % SITES_PER_WORD) * BITS_PER_SITE;
+        (self.words[index / SITES_PER_WORD] >> shift) & FULL
+    }
+
+    #[inline]
+    fn set(&mut self, index: usize, bits: u64) {
+        debug_assert!(index < self.length);
+        let shift = (index % SITES_PER_WORD) * BITS_PER_SITE;
+        let word = &mut self.words[index / SITES_PER_WORD];
+        *word = (*word & !(FULL << shift)) | (bits << shift);
+    }
+
+    fn count(&self, from: usize, to: usize, pattern: u64) -> usize {
+        if from >= to {
+            return 0;
+        }
+        let first = from / SITES_PER_WORD;
+        let last = (to - 1) / SITES_PER_WORD;
+        let mut count = 0;
+        for word_index in first..last + 1 {
+            let mut mask = pattern;
+            if word_index == first {
+                mask &= !0 << ((from % SITES_PER_WORD) * BITS_PER_SITE);
+            }
+            if word_index == last {
+                let end = (to - 1) % SITES_PER_WORD + 1;
+                if end < SITES_PER_WORD {
+                    mask &= !(!0 << (end * BITS_PER_SITE));
+                }
+            }
+            count += (self.words[word_index] & mask).count_ones() as usize;
+        }
+        count
+    }
+}
@@ -0,0 +1,268 @@
+use std::io::{self, Read, Write};
+
+use number_reader::invalid_data;
+use union_find::CompactRankedQuickUnion;
+
+use super::{BruteForcePercolation, UnionFindPercolation, HackUnionFindPercolation};
+use super::ext::PercolationBase;
+use super::listener::{PercolationListener, NoListener};
+use super::sites::SiteStates;
+
+const MAGIC: &'static [u8; 4] = b"PERC";
+const VERSION: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Kind {
+    BruteForce = 1,
+    UnionFind = 2,
+    HackUnionFind = 3
+}
+
+impl<L: PercolationListener> BruteForcePercolation<L> {
+
+    pub fn save<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_header(writer, Kind::BruteForce)?;
+        write_base(writer, &self.base)
+    }
+}
+
+impl BruteForcePercolation {
+
+    pub fn load<R: Read>(reader: &mut R) -> io::Result<BruteForcePercolation> {
+        read_header(reader, Kind::BruteForce)?;
+        Ok(BruteForcePercolation {
+            base: read_base(reader)?,
+            listener: NoListener
+        })
+    }
+}
+
+impl<L: PercolationListener> UnionFindPercolation<L> {
+
+    pub fn save<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.base.boundaries().is_some() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "snapshots only keep top to bottom percolation"));
+        }
+        write_header(writer, Kind::UnionFind)?;
+        write_base(writer, &self.base)?;
+        write_union_find(writer, &self.union_find, self.virtual_cell)
+    }
+}
+
+impl UnionFindPercolation {
+
+    pub fn load<R: Read>(reader: &mut R) -> io::Result<UnionFindPercolation> {
+        read_header(reader, Kind::UnionFind)?;
+        let base = read_base(reader)?;
+        let length = base.side_size() * base.side_size();
+        let elements = length.checked_add(1).ok_or_else(|| too_large(base.side_size()))?;
+        let (union_find, virtual_cell) = read_union_find(reader, elements)?;
+        if virtual_cell != length {
+            return Err(invalid_data(format!("virtual cell {} does not match side size {}", virtual_cell, base.side_size())));
+        }
+        Ok(UnionFindPercolation {
+            union_find: union_find,
+            virtual_cell: virtual_cell,
+            base: base,
+            listener: NoListener
+        })
+    }
+}
+
+impl<L: PercolationListener> HackUnionFindPercolation<L> {
+
+    pub fn save<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_header(writer, Kind::HackUnionFind)?;
+        write_u64(writer, self.side_size)?;
+        write_states(writer, &self.states)?;
+        write_union_find(writer, &self.union_find, self.virtual_cell)
+    }
+}
+
+impl HackUnionFindPercolation {
+
+    pub fn load<R: Read>(reader: &mut R) -> io::Result<HackUnionFindPercolation> {
+        read_header(reader, Kind::HackUnionFind)?;
+        let side_size = read_side_size(reader)?;
+        let power_of_two = side_size.checked_next_power_of_two().ok_or_else(|| too_large(side_size))?;
+        let lg_2 = power_of_two.trailing_zeros() as usize;
+        let length = side_size.checked_mul(power_of_two).ok_or_else(|| too_large(side_size))?;
+        let elements = length.checked_add(1).ok_or_else(|| too_large(side_size))?;
+        let states = read_states(reader, length)?;
+        let padding_open = (0..length).any(|index| index & (power_of_two - 1) >= side_size && states.is_open(index));
+        if padding_open {
+            return Err(invalid_data("open site outside of the grid".to_owned()));
+        }
+        let (union_find, virtual_cell) = read_union_find(reader, elements)?;
+        if virtual_cell != length {
+            return Err(invalid_data(format!("virtual cell {} does not match side size {}", virtual_cell, side_size)));
+        }
+        let percolated = states.any_full_in((side_size - 1) << lg_2, length);
+        Ok(HackUnionFindPercolation {
+            union_find: union_find,
+            virtual_cell: virtual_cell,
+            states: states,
+            side_size: side_size,
+            lg_2: lg_2,
+            mask: power_of_two - 1,
+            percolated: percolated,
+            listener: NoListener
+        })
+    }
+}
+
+fn write_header<W: Write>(writer: &mut W, kind: Kind) -> io::Result<()> {
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[VERSION as u8, (VERSION >> 8) as u8, kind as u8])
+}
+
+fn read_header<R: Read>(reader: &mut R, kind: Kind) -> io::Result<()> {
+    let mut magic = [0; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(invalid_data("not a percolation snapshot".to_owned()));
+    }
+    let mut version = [0; 2];
+    reader.read_exact(&mut version)?;
+    let version = version[0] as u16 | (version[1] as u16) << 8;
+    if version != VERSION {
+        return Err(invalid_data(format!("unsupported snapshot version {}, expected {}", version, VERSION)));
+    }
+    let stored = read_u8(reader)?;
+    if stored != kind as u8 {
+        return Err(invalid_data(format!("snapshot of kind {} cant be loaded as {:?}", stored, kind)));
+    }
+    Ok(())
+}
+
+fn write_base<W: Write>(writer: &mut W, base: &PercolationBase) -> io::Result<()> {
+    write_u64(writer, base.side_size())?;
+    write_states(writer, base.states())
+}
+
+fn read_base<R: Read>(reader: &mut R) -> io::Result<PercolationBase> {
+    let side_size = read_side_size(reader)?;
+    let length = side_size.checked_mul(side_size).ok_or_else(|| too_large(side_size))?;
+    let states = read_states(reader, length)?;
+    Ok(PercolationBase::from_states(side_size, states))
+}
+
+fn read_side_size<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let side_size = read_u64(reader)?;
+    if side_size == 0 {
+        Err(invalid_data("side size of a snapshot must be positive".to_owned()))
+    }
+    else {
+        Ok(side_size)
+    }
+}
+
+fn write_states<W: Write>(writer: &mut W, states: &SiteStates) -> io::Result<()> {
+    write_u64(writer, states.len())?;
+    for &word in states.words() {
+        writer.write_all(&word_bytes(word))?;
+    }
+    Ok(())
+}
+
+fn read_states<R: Read>(reader: &mut R, expected: usize) -> io::Result<SiteStates> {
+    let length = read_u64(reader)?;
+    if length != expected {
+        return Err(invalid_data(format!("snapshot has {} sites, expected {}", length, expected)));
+    }
+    let bytes = read_bytes(reader, SiteStates::word_count(length), 8)?;
+    let words = bytes.chunks(8).map(word_from_bytes).collect();
+    SiteStates::from_words(length, words).ok_or_else(|| invalid_data("corrupted site states".to_owned()))
+}
+
+fn write_union_find<W: Write>(writer: &mut W, union_find: &CompactRankedQuickUnion, virtual_cell: usize)
+        -> io::Result<()> {
+    write_u64(writer, virtual_cell)?;
+    write_u64(writer, union_find.parents().len())?;
+    for (&parent, &rank) in union_find.parents().iter().zip(union_find.ranks()) {
+        write_u64(writer, parent as usize)?;
+        write_u8(writer, rank)?;
+    }
+    Ok(())
+}
+
+fn read_union_find<R: Read>(reader: &mut R, expected: usize) -> io::Result<(CompactRankedQuickUnion, usize)> {
+    let virtual_cell = read_u64(reader)?;
+    let count = read_u64(reader)?;
+    if count != expected {
+        return Err(invalid_data(format!("snapshot has {} union find elements, expected {}", count, expected)));
+    }
+    let bytes = read_bytes(reader, count, 9)?;
+    let mut parents = Vec::with_capacity(count);
+    let mut ranks = Vec::with_capacity(count);
+    for element in bytes.chunks(9) {
+        let parent = word_from_bytes(&element[..8]);
+        if parent > u32::max_value() as u64 {
+            return Err(invalid_data(format!("union find parent {} is too large", parent)));
+        }
+        parents.push(parent as u32);
+        ranks.push(element[8]);
+    }
+    match CompactRankedQuickUnion::from_parts(parents, ranks) {
+        Some(union_find) => Ok((union_find, virtual_cell)),
+        None => Err(invalid_data("corrupted union find".to_owned())),
+    }
+}
+
+fn word_bytes(word: u64) -> [u8; 8] {
+    let mut bytes = [0; 8];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = (word >> (8 * i)) as u8;
+    }
+    bytes
+}
+
+fn word_from_bytes(bytes: &[u8]) -> u64 {
+    bytes.iter().rev().fold(0, |word, &byte| word << 8 | byte as u64)
+}
+
+// Reads as much as the input really holds instead of trusting a stored count with the allocation.
+fn read_bytes<R: Read>(reader: &mut R, count: usize, size: usize) -> io::Result<Vec<u8>> {
+    let length = count.checked_mul(size).ok_or_else(|| invalid_data(format!("{} records do not fit in memory", count)))?;
+    let mut bytes = Vec::new();
+    reader.take(length as u64).read_to_end(&mut bytes)?;
+    if bytes.len() < length {
+        Err(io::Error::new(io::ErrorKind::UnexpectedEof, "snapshot ends before all data is read"))
+    }
+    else {
+        Ok(bytes)
+    }
+}
+
+fn write_u64<W: Write>(writer: &mut W, value: usize) -> io::Result<()> {
+    writer.write_all(&word_bytes(value as u64))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    to_usize(word_from_bytes(&bytes))
+}
+
+fn to_usize(value: u64) -> io::Result<usize> {
+    if value > ::std::usize::MAX as u64 {
+        Err(invalid_data(format!("value {} does not fit in memory", value)))
+    }
+    else {
+        Ok(value as usize)
+    }
+}
+
+fn write_u8<W: Write>(writer: &mut W, value: u8) -> io::Result<()> {
+    writer.write_all(&[value])
+}
+
+fn read_u8<R: Read>(reader: &mut R) -> io::Result<u8> {
+    let mut byte = [0; 1];
+    reader.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+fn too_large(side_size: usize) -> io::Error {
+    invalid_data(format!("grid of side size {} does not fit in memory", side_size))
+}
@@ -0,0 +1,39 @@
+pub fn top_row(side_size: usize) -> Vec<(usize, usize)> {
+    (1..side_size + 1).map(|col| (1, col)).collect()
+}
+
+pub fn bottom_row(side_size: usize) -> Vec<(usize, usize)> {
+    (1..side_size + 1).map(|col| (side_size, col)).collect()
+}
+
+pub fn left_column(side_size: usize) -> Vec<(usize, usize)> {
+    (1..side_size + 1).map(|row| (row, 1)).collect()
+}
+
+pub fn right_column(side_size: usize) -> Vec<(usize, usize)> {
+    (1..side_size + 1).map(|row| (row, side_size)).collect()
+}
+
+pub fn boundary(side_size: usize) -> Vec<(usize, usize)> {
+    let mut sites = Vec::new();
+    for row in 1..side_size + 1 {
+        for col in 1..side_size + 1 {
+            if row == 1 || row == side_size || col == 1 || col == side_size {
+                sites.push((row, col));
+            }
+        }
+    }
+    sites
+}
+
+pub fn center(side_size: usize) -> Vec<(usize, usize)> {
+    let low = (side_size + 1) / 2;
+    let high = side_size / 2 + 1;
+    let mut sites = Vec::new();
+    for row in low..high + 1 {
+        for col in low..high + 1 {
+            sites.push((row, col));
+        }
+    }
+    sites
+}
@@ -0,0 +1,187 @@
+use std::sync::Arc;
+use std::thread;
+
+use rand::{Rng, SeedableRng, XorShiftRng};
+
+use super::Percolation;
+use super::bond::BondPercolation;
+use super::cubic::Percolation3D;
+use super::directed::{DirectedPercolation, Directions};
+
+const CONFIDENCE_95: f64 = 1.96;
+
+pub struct PercolationStats {
+    thresholds: Vec<f64>
+}
+
+impl PercolationStats {
+
+    pub fn new(thresholds: Vec<f64>) -> PercolationStats {
+        PercolationStats {
+            thresholds: thresholds
+        }
+    }
+
+    pub fn parallel<P, F>(side_size: usize, trials: usize, threads: usize, seed: u64, new: F) -> PercolationStats
+            where P: Percolation, F: Fn(usize) -> P + Send + Sync + 'static {
+        PercolationStats::parallel_trials(trials, threads, move |trial| {
+            let mut rng = trial_rng(seed, trial);
+            let mut percolation = new(side_size);
+            site_threshold(&mut percolation, &mut rng)
+        })
+    }
+
+    pub fn parallel_trials<F>(trials: usize, threads: usize, threshold: F) -> PercolationStats
+            where F: Fn(usize) -> f64 + Send + Sync + 'static {
+        let threads = if threads == 0 { 1 } else { threads };
+        let threshold = Arc::new(threshold);
+        let handles = (0..threads)
+            .map(|worker| {
+                let threshold = threshold.clone();
+                thread::spawn(move || {
+                    (worker..trials).step_by(threads)
+                        .map(|trial| (trial, threshold(trial)))
+                        .collect::<Vec<(usize, f64)>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut thresholds = vec![0.0; trials];
+        for handle in handles {
+            match handle.join() {
+                Ok(results) => for (trial, threshold) in results {
+                    thresholds[trial] = threshold;
+                },
+                Err(_) => panic!("percolation trial thread panicked"),
+            }
+        }
+        PercolationStats::new(thresholds)
+    }
+
+    pub fn cubic<R: Rng>(side_size: usize, trials: usize, rng: &mut R) -> PercolationStats {
+        let thresholds = (0..trials).map(|_| cubic_threshold(side_size, rng)).collect();
+        PercolationStats::new(thresholds)
+    }
+
+    pub fn bond<R: Rng>(side_size: usize, trials: usize, rng: &mut R) -> PercolationStats {
+        let thresholds = (0..trials).map(|_| bond_threshold(side_size, rng)).collect();
+        PercolationStats::new(thresholds)
+    }
+
+    pub fn directed<R: Rng>(side_size: usize, trials: usize, directions: Directions, rng: &mut R) -> PercolationStats {
+        let thresholds = (0..trials)
+            .map(|_| site_threshold(&mut DirectedPercolation::new(side_size, directions), rng))
+            .collect();
+        PercolationStats::new(thresholds)
+    }
+
+    pub fn thresholds(&self) -> &[f64] {
+        &self.thresholds
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.thresholds.iter().fold(0.0, |sum, t| sum + t) / self.thresholds.len() as f64
+    }
+
+    pub fn stddev(&self) -> f64 {
+        if self.thresholds.len() < 2 {
+            return 0.0;
+        }
+        let mean = self.mean();
+        let squares = self.thresholds.iter().fold(0.0, |sum, t| sum + (t - mean) * (t - mean));
+        (squares / (self.thresholds.len() - 1) as f64).sqrt()
+    }
+
+    pub fn confidence_lo(&self) -> f64 {
+        self.mean() - self.confidence_delta()
+    }
+
+    pub fn confidence_hi(&self) -> f64 {
+        self.mean() + self.confidence_delta()
+    }
+
+    fn confidence_delta(&self) -> f64 {
+        CONFIDENCE_95 * self.stddev() / (self.thresholds.len() as f64).sqrt()
+    }
+}
+
+pub fn random_order<R: Rng>(length: usize, rng: &mut R) -> Vec<usize> {
+    let mut order = (0..length).collect::<Vec<usize>>();
+    rng.shuffle(&mut order);
+    order
+}
+
+pub fn trial_rng(seed: u64, trial: usize) -> XorShiftRng {
+    let mut state = seed ^ (trial as u64).wrapping_mul(0xd1b54a32d192ed03);
+    let first = split_mix(&mut state);
+    let second = split_mix(&mut state);
+    let words = [first as u32, (first >> 32) as u32, second as u32, (second >> 32) as u32 | 1];
+    XorShiftRng::from_seed(words)
+}
+
+fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+pub fn site_threshold<P: Percolation + ?Sized, R: Rng>(percolation: &mut P, rng: &mut R) -> f64 {
+    let side_size = percolation.side_size();
+    let length = side_size * side_size;
+    let mut opened = 0;
+    for index in random_order(length, rng) {
+        let row = index / side_size + 1;
+        let col = index % side_size + 1;
+        if !percolation.is_open(row, col) {
+            percolation.open(row, col);
+            opened += 1;
+            if percolation.percolates() {
+                break;
+            }
+        }
+    }
+    opened as f64 / length as f64
+}
+
+pub fn cubic_threshold<R: Rng>(side_size: usize, rng: &mut R) -> f64 {
+    let layer_size = side_size * side_size;
+    let length = layer_size * side_size;
+    let mut percolation = Percolation3D::new(side_size);
+    let mut opened = 0;
+    for index in random_order(length, rng) {
+        let layer = index / layer_size + 1;
+        let row = index % layer_size / side_size + 1;
+        let col = index % side_size + 1;
+        percolation.open(layer, row, col);
+        opened += 1;
+        if percolation.percolates() {
+            break;
+        }
+    }
+    opened as f64 / length as f64
+}
+
+pub fn bond_threshold<R: Rng>(side_size: usize, rng: &mut R) -> f64 {
+    let mut percolation = BondPercolation::new(side_size);
+    let horizontal = side_size * (side_size - 1);
+    let length = percolation.number_of_bonds();
+    let mut opened = 0;
+    for bond in random_order(length, rng) {
+        if bond < horizontal {
+            let row = bond / (side_size - 1) + 1;
+            let col = bond % (side_size - 1) + 1;
+            percolation.open_bond((row, col), (row, col + 1));
+        }
+        else {
+            let row = (bond - horizontal) / side_size + 1;
+            let col = (bond - horizontal) % side_size + 1;
+            percolation.open_bond((row, col), (row + 1, col));
+        }
+        opened += 1;
+        if percolation.percolates() {
+            break;
+        }
+    }
+    opened as f64 / length as f64
+}
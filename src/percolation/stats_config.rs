@@ -0,0 +1,99 @@
+use super::UnionFindPercolation;
+use super::directed::{DirectedPercolation, Directions};
+use super::factory::Implementation;
+use super::source_sink;
+use super::stats::{PercolationStats, trial_rng, site_threshold, bond_threshold, cubic_threshold};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Lattice {
+    Site,
+    Bond,
+    Cubic,
+    Directed
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Boundary {
+    TopBottom,
+    LeftRight,
+    BoundaryCenter
+}
+
+// The implementation only matters for the site lattice with top-bottom boundary.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct StatsConfig {
+    pub size: usize,
+    pub trials: usize,
+    pub threads: usize,
+    pub implementation: Implementation,
+    pub seed: u64,
+    pub lattice: Lattice,
+    pub boundary: Boundary
+}
+
+impl StatsConfig {
+
+    pub fn check(&self) -> Result<(), String> {
+        if self.size == 0 || self.trials == 0 || self.threads == 0 {
+            return Err("size, trials and threads must be positive".to_owned());
+        }
+        if self.lattice == Lattice::Bond && self.size < 2 {
+            return Err("bond lattice needs size of at least 2 to have any bonds".to_owned());
+        }
+        if self.lattice != Lattice::Site && self.boundary != Boundary::TopBottom {
+            return Err("boundary options are only supported on the site lattice".to_owned());
+        }
+        Ok(())
+    }
+
+    pub fn uses_implementation(&self) -> bool {
+        self.lattice == Lattice::Site && self.boundary == Boundary::TopBottom
+    }
+
+    pub fn lattice_name(&self) -> &'static str {
+        match self.lattice {
+            Lattice::Site => "site",
+            Lattice::Bond => "bond",
+            Lattice::Cubic => "cubic",
+            Lattice::Directed => "directed",
+        }
+    }
+
+    pub fn boundary_name(&self) -> &'static str {
+        match self.boundary {
+            Boundary::TopBottom => "top-bottom",
+            Boundary::LeftRight => "left-right",
+            Boundary::BoundaryCenter => "boundary-center",
+        }
+    }
+
+    pub fn run(&self) -> PercolationStats {
+        let config = *self;
+        PercolationStats::parallel_trials(self.trials, self.threads, move |trial| config.threshold(trial))
+    }
+
+    pub fn threshold(&self, trial: usize) -> f64 {
+        let mut rng = trial_rng(self.seed, trial);
+        let size = self.size;
+        match (self.lattice, self.boundary) {
+            (Lattice::Site, Boundary::TopBottom) => {
+                let mut percolation = self.implementation.create(size);
+                site_threshold(&mut *percolation, &mut rng)
+            },
+            (Lattice::Site, Boundary::LeftRight) => {
+                let sources = source_sink::left_column(size);
+                let sinks = source_sink::right_column(size);
+                site_threshold(&mut UnionFindPercolation::with_boundaries(size, &sources, &sinks), &mut rng)
+            },
+            (Lattice::Site, Boundary::BoundaryCenter) => {
+                let sources = source_sink::boundary(size);
+                let sinks = source_sink::center(size);
+                site_threshold(&mut UnionFindPercolation::with_boundaries(size, &sources, &sinks), &mut rng)
+            },
+            (Lattice::Bond, _) => bond_threshold(size, &mut rng),
+            (Lattice::Cubic, _) => cubic_threshold(size, &mut rng),
+            (Lattice::Directed, _) =>
+                site_threshold(&mut DirectedPercolation::new(size, Directions::down_and_sideways()), &mut rng),
+        }
+    }
+}
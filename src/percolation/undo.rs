@@ -0,0 +1,167 @@
+use union_find::WeightedQuickUnion;
+
+use super::{Percolation, PercolationError, check_side_size};
+
+struct Link {
+    child: usize,
+    parent: usize,
+    touched_bottom: bool
+}
+
+struct Opening {
+    row: usize,
+    col: usize,
+    links: Vec<Link>,
+    percolated: bool
+}
+
+pub struct UndoablePercolation {
+    union_find: WeightedQuickUnion,
+    virtual_cell: usize,
+    openness: Vec<bool>,
+    touches_bottom: Vec<bool>,
+    side_size: usize,
+    open_sites: usize,
+    percolated: bool,
+    history: Vec<Opening>,
+    undone: Vec<(usize, usize)>
+}
+
+impl UndoablePercolation {
+
+    pub fn new(side_size: usize) -> UndoablePercolation {
+        match UndoablePercolation::try_new(side_size) {
+            Ok(percolation) => percolation,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_new(side_size: usize) -> Result<UndoablePercolation, PercolationError> {
+        check_side_size(side_size)?;
+        let length = side_size * side_size;
+        Ok(UndoablePercolation {
+            // Union by size without path compression keeps every union a single parent link,
+            // so the links an opening made can be cut again in reverse order.
+            union_find: WeightedQuickUnion::new(length + 1),
+            virtual_cell: length,
+            openness: vec![false; length],
+            touches_bottom: (0..length + 1).map(|index| index < length && index >= length - side_size).collect(),
+            side_size: side_size,
+            open_sites: 0,
+            percolated: false,
+            history: Vec::new(),
+            undone: Vec::new()
+        })
+    }
+
+    pub fn undo(&mut self) -> Option<(usize, usize)> {
+        let opening = match self.history.pop() {
+            Some(opening) => opening,
+            None => return None,
+        };
+        for link in opening.links.iter().rev() {
+            self.union_find.unlink(link.child, link.parent);
+            self.touches_bottom[link.parent] = link.touched_bottom;
+        }
+        let index = self.cell_index(opening.row, opening.col);
+        self.openness[index] = false;
+        self.open_sites -= 1;
+        self.percolated = opening.percolated;
+        self.undone.push((opening.row, opening.col));
+        Some((opening.row, opening.col))
+    }
+
+    pub fn redo(&mut self) -> Option<(usize, usize)> {
+        let site = match self.undone.pop() {
+            Some(site) => site,
+            None => return None,
+        };
+        self.record(site.0, site.1);
+        Some(site)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.history.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    fn cell_index(&self, row: usize, col: usize) -> usize {
+        (row - 1) * self.side_size + col - 1
+    }
+
+    fn record(&mut self, row: usize, col: usize) {
+        let index = self.cell_index(row, col);
+        let side_size = self.side_size;
+        let mut opening = Opening {
+            row: row,
+            col: col,
+            links: Vec::new(),
+            percolated: self.percolated
+        };
+        self.openness[index] = true;
+        self.open_sites += 1;
+        if row == 1 {
+            self.link(index, self.virtual_cell, &mut opening);
+        }
+        if row > 1 && self.openness[index - side_size] {
+            self.link(index, index - side_size, &mut opening);
+        }
+        if col > 1 && self.openness[index - 1] {
+            self.link(index, index - 1, &mut opening);
+        }
+        if col < side_size && self.openness[index + 1] {
+            self.link(index, index + 1, &mut opening);
+        }
+        if row < side_size && self.openness[index + side_size] {
+            self.link(index, index + side_size, &mut opening);
+        }
+        let top = self.union_find.root(self.virtual_cell);
+        self.percolated = self.percolated || self.touches_bottom[top];
+        self.history.push(opening);
+    }
+
+    fn link(&mut self, p: usize, q: usize, opening: &mut Opening) {
+        if let Some((child, parent)) = self.union_find.link(p, q) {
+            opening.links.push(Link {
+                child: child,
+                parent: parent,
+                touched_bottom: self.touches_bottom[parent]
+            });
+            self.touches_bottom[parent] = self.touches_bottom[parent] || self.touches_bottom[child];
+        }
+    }
+}
+
+impl Percolation for UndoablePercolation {
+
+    fn open(&mut self, row: usize, col: usize) {
+        if !self.is_open(row, col) {
+            self.undone.clear();
+            self.record(row, col);
+        }
+    }
+
+    fn is_open(&self, row: usize, col: usize) -> bool {
+        self.openness[self.cell_index(row, col)]
+    }
+
+    fn is_full(&self, row: usize, col: usize) -> bool {
+        let index = self.cell_index(row, col);
+        self.openness[index] && self.union_find.root(index) == self.union_find.root(self.virtual_cell)
+    }
+
+    fn percolates(&self) -> bool {
+        self.percolated
+    }
+
+    fn side_size(&self) -> usize {
+        self.side_size
+    }
+
+    fn number_of_open_sites(&self) -> usize {
+        self.open_sites
+    }
+}
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 use std::hash::BuildHasherDefault;
+use std::io::{self, BufRead, Write};
 
 use fnv::FnvHasher;
 
 use generator::{Generator, DefaultGenerator};
+use number_reader;
 
 pub trait UnionFind {
 
@@ -105,30 +107,48 @@ impl WeightedQuickUnion {
             sizes: sizes
         }
     }
-}
-
-impl UnionFind for WeightedQuickUnion {
 
-    fn union(&mut self, p: usize, q: usize) {
-        let p_root = self.find(p);
-        let q_root = self.find(q);
-        if self.sizes[p_root] <= self.sizes[q_root] {
-            self.points[p_root] = q_root;
-            self.sizes[q_root] += self.sizes[p_root];
-        }
-        else {
-            self.points[q_root] = p_root;
-            self.sizes[p_root] += self.sizes[q_root];
-        }
+    pub fn size(&mut self, p: usize) -> usize {
+        let root = self.find(p);
+        self.sizes[root]
     }
 
-    fn find(&mut self, p: usize) -> usize {
+    pub fn root(&self, p: usize) -> usize {
         let mut point = p;
         while point != self.points[point] {
             point = self.points[point];
         }
         point
     }
+
+    pub fn link(&mut self, p: usize, q: usize) -> Option<(usize, usize)> {
+        let p_root = self.root(p);
+        let q_root = self.root(q);
+        if p_root == q_root {
+            return None;
+        }
+        let (child, parent) = if self.sizes[p_root] <= self.sizes[q_root] { (p_root, q_root) } else { (q_root, p_root) };
+        self.points[child] = parent;
+        self.sizes[parent] += self.sizes[child];
+        Some((child, parent))
+    }
+
+    pub fn unlink(&mut self, child: usize, parent: usize) {
+        debug_assert!(self.points[child] == parent && self.points[parent] == parent);
+        self.points[child] = child;
+        self.sizes[parent] -= self.sizes[child];
+    }
+}
+
+impl UnionFind for WeightedQuickUnion {
+
+    fn union(&mut self, p: usize, q: usize) {
+        self.link(p, q);
+    }
+
+    fn find(&mut self, p: usize) -> usize {
+        self.root(p)
+    }
 }
 
 type FasterHasher = BuildHasherDefault<FnvHasher>;
@@ -158,6 +178,11 @@ impl PathCompressionWeightedQuickUnion {
             sizes: sizes
         }
     }
+
+    pub fn size(&mut self, p: usize) -> usize {
+        let root = self.find(p);
+        self.sizes[&root]
+    }
 }
 
 impl UnionFind for PathCompressionWeightedQuickUnion {
@@ -165,6 +190,9 @@ impl UnionFind for PathCompressionWeightedQuickUnion {
     fn union(&mut self, p: usize, q: usize) {
         let p_root = self.find(p);
         let q_root = self.find(q);
+        if p_root == q_root {
+            return;
+        }
         if self.sizes[&p_root] <= self.sizes[&q_root] {
             let p_entry = self.points.entry(p_root).or_insert(p_root);
             *p_entry = q_root;
@@ -194,3 +222,137 @@ impl UnionFind for PathCompressionWeightedQuickUnion {
         point
     }
 }
+
+// One u32 parent and one rank byte per point, five bytes in all. Ranks bound the tree heights
+// by log2 of the size, so a byte is plenty, and union by rank needs no component sizes.
+pub struct CompactRankedQuickUnion {
+    parents: Vec<u32>,
+    ranks: Vec<u8>
+}
+
+impl CompactRankedQuickUnion {
+
+    pub fn new(size: usize) -> CompactRankedQuickUnion {
+        assert!(fits_u32(size), "compact union find holds at most 2^32 points, got {}", size);
+        CompactRankedQuickUnion {
+            parents: (0..size).map(|p| p as u32).collect(),
+            ranks: vec![0; size]
+        }
+    }
+
+    pub fn parents(&self) -> &[u32] {
+        &self.parents
+    }
+
+    pub fn ranks(&self) -> &[u8] {
+        &self.ranks
+    }
+
+    // Every parent must outrank its children, which rules out cycles in the restored forest.
+    pub fn from_parts(parents: Vec<u32>, ranks: Vec<u8>) -> Option<CompactRankedQuickUnion> {
+        if parents.len() != ranks.len() || !fits_u32(parents.len()) {
+            return None;
+        }
+        let valid = parents.iter().enumerate()
+            .all(|(p, &parent)| {
+                let parent = parent as usize;
+                parent == p || (parent < parents.len() && ranks[parent] > ranks[p])
+            });
+        if valid {
+            Some(CompactRankedQuickUnion {
+                parents: parents,
+                ranks: ranks
+            })
+        }
+        else {
+            None
+        }
+    }
+}
+
+fn fits_u32(size: usize) -> bool {
+    size == 0 || (size - 1) as u64 <= u32::max_value() as u64
+}
+
+impl UnionFind for CompactRankedQuickUnion {
+
+    fn union(&mut self, p: usize, q: usize) {
+        let p_root = self.find(p);
+        let q_root = self.find(q);
+        if p_root == q_root {
+            return;
+        }
+        if self.ranks[p_root] < self.ranks[q_root] {
+            self.parents[p_root] = q_root as u32;
+        }
+        else if self.ranks[p_root] > self.ranks[q_root] {
+            self.parents[q_root] = p_root as u32;
+        }
+        else {
+            self.parents[p_root] = q_root as u32;
+            self.ranks[q_root] += 1;
+        }
+    }
+
+    fn find(&mut self, p: usize) -> usize {
+        let mut point = p;
+        while point != self.parents[point] as usize {
+            let grandparent = self.parents[self.parents[point] as usize];
+            self.parents[point] = grandparent;
+            point = grandparent as usize;
+        }
+        point
+    }
+}
+
+// Pairs are read and checked against the size one line at a time, so clients can connect them
+// while the input is still streaming in.
+pub struct Pairs<R> {
+    pairs: number_reader::Pairs<R>,
+    size: usize
+}
+
+impl<R: BufRead> Iterator for Pairs<R> {
+
+    type Item = io::Result<(usize, usize)>;
+
+    fn next(&mut self) -> Option<io::Result<(usize, usize)>> {
+        let size = self.size;
+        self.pairs.next().map(|pair| pair.and_then(|(p, q)| {
+            if p >= size || q >= size {
+                Err(number_reader::invalid_data(format!("pair '{} {}' is out of 0..{}", p, q, size)))
+            }
+            else {
+                Ok((p, q))
+            }
+        }))
+    }
+}
+
+pub fn pairs<R: BufRead>(reader: R) -> io::Result<(usize, Pairs<R>)> {
+    let (size, pairs) = number_reader::pairs(reader, "size", "p", "q")?;
+    let pairs = Pairs {
+        pairs: pairs,
+        size: size
+    };
+    Ok((size, pairs))
+}
+
+pub fn read_pairs<R: BufRead>(reader: R) -> io::Result<(usize, Vec<(usize, usize)>)> {
+    let (size, pairs) = pairs(reader)?;
+    Ok((size, pairs.collect::<io::Result<Vec<_>>>()?))
+}
+
+pub fn connect_pairs<U, I, W>(union_find: &mut U, size: usize, pairs: I, out: &mut W) -> io::Result<usize>
+        where U: UnionFind + ?Sized, I: IntoIterator<Item = io::Result<(usize, usize)>>, W: Write {
+    let mut components = size;
+    for pair in pairs {
+        let (p, q) = pair?;
+        if !union_find.connected(p, q) {
+            union_find.union(p, q);
+            components -= 1;
+            writeln!(out, "{} {}", p, q)?;
+        }
+    }
+    Ok(components)
+}
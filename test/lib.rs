@@ -5,6 +5,7 @@
 #![allow(unused_mut)]
 
 extern crate algorithms;
+extern crate rand;
 
 #[macro_use]
 extern crate expectest;
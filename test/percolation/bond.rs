@@ -0,0 +1,108 @@
+pub use algorithms::percolation::PercolationError;
+pub use algorithms::percolation::bond::BondPercolation;
+pub use algorithms::percolation::stats::PercolationStats;
+
+pub use rand::{SeedableRng, XorShiftRng};
+
+pub use expectest::prelude::{be_true, be_false, be_err, be_equal_to, be_close_to};
+
+describe! bond_percolation_tests {
+
+    before_each {
+        const SIDE_SIZE: usize = 4;
+        let mut percolation = BondPercolation::new(SIDE_SIZE);
+    }
+
+    it "should open a bond in both directions" {
+        percolation.open_bond((2, 2), (2, 3));
+
+        expect!(percolation.is_bond_open((2, 3), (2, 2))).to(be_true());
+    }
+
+    it "should not open neighbor bonds" {
+        percolation.open_bond((2, 2), (2, 3));
+
+        expect!(percolation.is_bond_open((2, 2), (3, 2))).to(be_false());
+    }
+
+    it "should be full for sites on the top" {
+        expect!(percolation.is_full(1, 3)).to(be_true());
+    }
+
+    it "should not be full for sites without open bonds to the top" {
+        percolation.open_bond((2, 2), (2, 3));
+
+        expect!(percolation.is_full(2, 2)).to(be_false());
+    }
+
+    it "should be full if a site connects to the top through open bonds" {
+        percolation.open_bond((3, 1), (3, 2));
+        percolation.open_bond((2, 1), (3, 1));
+        percolation.open_bond((1, 1), (2, 1));
+
+        expect!(percolation.is_full(3, 2)).to(be_true());
+    }
+
+    it "should percolate when open bonds connect top and bottom" {
+        for row in 1..SIDE_SIZE {
+            percolation.open_bond((row, 3), (row + 1, 3));
+        }
+
+        expect!(percolation.percolates()).to(be_true());
+    }
+
+    it "should not percolate without a path of open bonds" {
+        for row in 1..SIDE_SIZE - 1 {
+            percolation.open_bond((row, 3), (row + 1, 3));
+        }
+
+        expect!(percolation.percolates()).to(be_false());
+    }
+
+    it "should not contain backwash" {
+        for row in 1..SIDE_SIZE {
+            percolation.open_bond((row, 1), (row + 1, 1));
+        }
+        percolation.open_bond((SIDE_SIZE, 3), (SIDE_SIZE, 4));
+
+        expect!(percolation.is_full(SIDE_SIZE, 4)).to(be_false());
+    }
+
+    it "should estimate bond threshold near 0.5" {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+
+        let stats = PercolationStats::bond(50, 30, &mut rng);
+
+        expect!(stats.mean()).to(be_close_to(0.5).delta(0.05));
+    }
+}
+
+describe! bond_percolation_validation_tests {
+
+    it "should reject empty grid" {
+        expect!(BondPercolation::try_new(0).err()).to(be_equal_to(Some(PercolationError::EmptyGrid)));
+    }
+
+    it "should open bond between neighbors" {
+        let mut percolation = BondPercolation::new(3);
+
+        expect!(percolation.try_open_bond((1, 1), (2, 1)).is_ok()).to(be_true());
+        expect!(percolation.try_is_bond_open((2, 1), (1, 1))).to(be_equal_to(Ok(true)));
+    }
+
+    it "should reject bond to site out of the grid" {
+        let mut percolation = BondPercolation::new(3);
+
+        expect!(percolation.try_open_bond((3, 3), (4, 3)).err())
+            .to(be_equal_to(Some(PercolationError::OutOfBounds { row: 4, col: 3, side_size: 3 })));
+        expect!(percolation.try_is_full(0, 1)).to(be_err());
+    }
+
+    it "should reject bond between sites that are not neighbors" {
+        let mut percolation = BondPercolation::new(3);
+
+        expect!(percolation.try_open_bond((1, 3), (2, 1)).err())
+            .to(be_equal_to(Some(PercolationError::NotNeighbors { first: (1, 3), second: (2, 1) })));
+        expect!(percolation.try_open_bond((2, 2), (2, 2))).to(be_err());
+    }
+}
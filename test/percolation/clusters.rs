@@ -0,0 +1,107 @@
+pub use algorithms::percolation::{Percolation, BruteForcePercolation, UnionFindPercolation};
+pub use algorithms::percolation::clusters::ClusterStats;
+
+pub use expectest::prelude::{be_equal_to, be_close_to};
+
+describe! cluster_stats_tests {
+
+    before_each {
+        let mut stats = ClusterStats::new(4);
+    }
+
+    it "should not have clusters on a blocked grid" {
+        expect!(stats.number_of_clusters()).to(be_equal_to(0));
+        expect!(stats.largest_cluster_size()).to(be_equal_to(0));
+        expect!(stats.mean_cluster_size()).to(be_close_to(0.0));
+    }
+
+    it "should merge neighbor open sites into one cluster" {
+        stats.open(2, 2);
+        stats.open(2, 3);
+        stats.open(3, 3);
+        stats.open(4, 1);
+
+        expect!(stats.number_of_clusters()).to(be_equal_to(2));
+        expect!(stats.largest_cluster_size()).to(be_equal_to(3));
+        expect!(stats.size_distribution().iter().map(|(&s, &n)| (s, n)).collect::<Vec<_>>())
+            .to(be_equal_to(vec![(1, 1), (3, 1)]));
+    }
+
+    it "should not count an open site twice" {
+        stats.open(2, 2);
+        stats.open(2, 2);
+
+        expect!(stats.number_of_open_sites()).to(be_equal_to(1));
+        expect!(stats.number_of_clusters()).to(be_equal_to(1));
+    }
+
+    it "should weigh spanning cluster separately" {
+        for row in 1..5 {
+            stats.open(row, 1);
+        }
+        stats.open(4, 2);
+        stats.open(2, 4);
+        stats.open(3, 4);
+
+        expect!(stats.spanning_cluster_mass()).to(be_equal_to(5));
+        expect!(stats.mean_cluster_size()).to(be_close_to(2.0));
+    }
+
+    it "should compute mean cluster size without spanning cluster" {
+        stats.open(1, 1);
+        stats.open(3, 1);
+        stats.open(3, 2);
+        stats.open(3, 4);
+
+        expect!(stats.spanning_cluster_mass()).to(be_equal_to(0));
+        expect!(stats.mean_cluster_size()).to(be_close_to((1.0 + 4.0 + 1.0) / 4.0));
+    }
+
+    it "should split a cluster when its bridge closes" {
+        stats.open(2, 1);
+        stats.open(2, 2);
+        stats.open(2, 3);
+        stats.close(2, 2);
+
+        expect!(stats.number_of_open_sites()).to(be_equal_to(2));
+        expect!(stats.number_of_clusters()).to(be_equal_to(2));
+        expect!(stats.largest_cluster_size()).to(be_equal_to(1));
+    }
+
+    it "should follow closed sites when attached as listener" {
+        let mut percolation = BruteForcePercolation::with_listener(4, ClusterStats::new(4));
+        for row in 1..5 {
+            percolation.open(row, 1);
+        }
+        percolation.close(3, 1);
+
+        let rebuilt = ClusterStats::from_percolation(&percolation);
+        expect!(percolation.listener().number_of_clusters()).to(be_equal_to(rebuilt.number_of_clusters()));
+        expect!(percolation.listener().spanning_cluster_mass()).to(be_equal_to(0));
+        expect!(percolation.listener().largest_cluster_size()).to(be_equal_to(2));
+    }
+
+    it "should be computed from any percolation grid" {
+        let mut percolation = BruteForcePercolation::new(4);
+        percolation.open(1, 1);
+        percolation.open(2, 1);
+        percolation.open(4, 4);
+
+        let stats = ClusterStats::from_percolation(&percolation);
+
+        expect!(stats.number_of_clusters()).to(be_equal_to(2));
+        expect!(stats.largest_cluster_size()).to(be_equal_to(2));
+    }
+
+    it "should be updated as sites open when attached as listener" {
+        let mut percolation = UnionFindPercolation::with_listener(4, ClusterStats::new(4));
+        percolation.open(1, 2);
+        percolation.open(2, 2);
+
+        expect!(percolation.listener().largest_cluster_size()).to(be_equal_to(2));
+
+        percolation.open(2, 3);
+
+        expect!(percolation.listener().largest_cluster_size()).to(be_equal_to(3));
+    }
+}
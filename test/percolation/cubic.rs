@@ -0,0 +1,82 @@
+pub use algorithms::percolation::PercolationError;
+pub use algorithms::percolation::cubic::Percolation3D;
+pub use algorithms::percolation::stats::PercolationStats;
+
+pub use rand::{SeedableRng, XorShiftRng};
+
+pub use expectest::prelude::{be_true, be_false, be_equal_to, be_close_to};
+
+describe! cubic_percolation_tests {
+
+    before_each {
+        const SIDE_SIZE: usize = 5;
+        let mut percolation = Percolation3D::new(SIDE_SIZE);
+    }
+
+    it "should open a site" {
+        percolation.open(2, 3, 4);
+
+        expect!(percolation.is_open(2, 3, 4)).to(be_true());
+    }
+
+    it "should be full when opened site is on the top face" {
+        percolation.open(1, 3, 4);
+
+        expect!(percolation.is_full(1, 3, 4)).to(be_true());
+    }
+
+    it "should not be full when opened site is not connected to the top face" {
+        percolation.open(2, 3, 4);
+        percolation.open(2, 3, 5);
+
+        expect!(percolation.is_full(2, 3, 4)).to(be_false());
+    }
+
+    it "should be full if a site connects to the top face through other layers" {
+        percolation.open(3, 1, 1);
+        percolation.open(2, 1, 1);
+        percolation.open(1, 1, 1);
+
+        expect!(percolation.is_full(3, 1, 1)).to(be_true());
+    }
+
+    it "should percolate when a column of sites crosses all layers" {
+        for layer in 1..SIDE_SIZE + 1 {
+            percolation.open(layer, 2, 2);
+        }
+
+        expect!(percolation.percolates()).to(be_true());
+    }
+
+    it "should not percolate when bottom face is not reached" {
+        for layer in 1..SIDE_SIZE {
+            percolation.open(layer, 2, 2);
+        }
+
+        expect!(percolation.percolates()).to(be_false());
+    }
+
+    it "should not contain backwash" {
+        for layer in 1..SIDE_SIZE + 1 {
+            percolation.open(layer, 2, 2);
+        }
+        percolation.open(SIDE_SIZE, 4, 4);
+
+        expect!(percolation.is_full(SIDE_SIZE, 4, 4)).to(be_false());
+    }
+
+    it "should estimate site threshold near 0.3116" {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+
+        let stats = PercolationStats::cubic(20, 30, &mut rng);
+
+        expect!(stats.mean()).to(be_close_to(0.3116).delta(0.05));
+    }
+}
+
+describe! cubic_percolation_construction_tests {
+
+    it "should reject empty grid" {
+        expect!(Percolation3D::try_new(0).err()).to(be_equal_to(Some(PercolationError::EmptyGrid)));
+    }
+}
@@ -0,0 +1,161 @@
+pub use rand::{SeedableRng, XorShiftRng};
+
+pub use algorithms::percolation::{Percolation, BruteForcePercolation};
+pub use algorithms::percolation::directed::{DirectedPercolation, Directions};
+pub use algorithms::percolation::stats::{PercolationStats, random_order};
+
+pub use expectest::prelude::{be_true, be_false, be_equal_to, be_greater_than, be_less_than};
+
+describe! directed_percolation_tests {
+
+    describe! down_and_sideways {
+
+        before_each {
+            let mut percolation = DirectedPercolation::new(4, Directions::down_and_sideways());
+        }
+
+        it "should fill opened site on the top row" {
+            percolation.open(1, 2);
+
+            expect!(percolation.is_full(1, 2)).to(be_true());
+        }
+
+        it "should fill downward and sideways" {
+            percolation.open(1, 1);
+            percolation.open(2, 1);
+            percolation.open(2, 2);
+            percolation.open(2, 3);
+
+            expect!(percolation.is_full(2, 3)).to(be_true());
+        }
+
+        it "should not fill upward" {
+            percolation.open(1, 1);
+            percolation.open(2, 1);
+            percolation.open(3, 1);
+            percolation.open(3, 2);
+            percolation.open(3, 3);
+            percolation.open(2, 3);
+
+            expect!(percolation.is_full(3, 3)).to(be_true());
+            expect!(percolation.is_full(2, 3)).to(be_false());
+        }
+
+        it "should not fill upward when the upper site is opened first" {
+            percolation.open(2, 3);
+            percolation.open(1, 1);
+            percolation.open(2, 1);
+            percolation.open(3, 1);
+            percolation.open(3, 2);
+            percolation.open(3, 3);
+
+            expect!(percolation.is_full(3, 3)).to(be_true());
+            expect!(percolation.is_full(2, 3)).to(be_false());
+        }
+
+        it "should percolate along a path that only goes down and sideways" {
+            percolation.open(1, 1);
+            percolation.open(2, 1);
+            percolation.open(2, 2);
+            percolation.open(2, 3);
+            percolation.open(3, 3);
+            percolation.open(4, 3);
+
+            expect!(percolation.percolates()).to(be_true());
+        }
+    }
+
+    it "should not percolate along a path that has to go up" {
+        let sites = [(1, 1), (2, 1), (3, 1), (3, 2), (3, 3), (2, 3), (2, 4), (2, 5), (3, 5), (4, 5), (5, 5)];
+        let mut directed = DirectedPercolation::new(5, Directions::down_and_sideways());
+        let mut undirected = DirectedPercolation::new(5, Directions::all());
+        for &(row, col) in sites.iter() {
+            directed.open(row, col);
+            undirected.open(row, col);
+        }
+
+        expect!(undirected.percolates()).to(be_true());
+        expect!(directed.percolates()).to(be_false());
+    }
+
+    describe! down_and_right {
+
+        before_each {
+            let mut percolation = DirectedPercolation::new(3, Directions::down_and_right());
+        }
+
+        it "should not fill to the left" {
+            percolation.open(2, 1);
+            percolation.open(2, 2);
+            percolation.open(1, 2);
+
+            expect!(percolation.is_full(2, 2)).to(be_true());
+            expect!(percolation.is_full(2, 1)).to(be_false());
+        }
+    }
+
+    it "should fill like the undirected percolation when every direction is allowed" {
+        let mut rng = XorShiftRng::from_seed([3, 5, 7, 11]);
+        let mut directed = DirectedPercolation::new(12, Directions::all());
+        let mut undirected = BruteForcePercolation::new(12);
+        for index in random_order(144, &mut rng) {
+            directed.open(index / 12 + 1, index % 12 + 1);
+            undirected.open(index / 12 + 1, index % 12 + 1);
+            expect!(directed.percolates()).to(be_equal_to(undirected.percolates()));
+        }
+        for row in 1..13 {
+            for col in 1..13 {
+                expect!(directed.is_full(row, col)).to(be_equal_to(undirected.is_full(row, col)));
+            }
+        }
+    }
+
+    it "should fill the sites reachable from the top row along allowed directions" {
+        const SIDE_SIZE: usize = 10;
+        let mut rng = XorShiftRng::from_seed([13, 17, 19, 23]);
+        let mut percolation = DirectedPercolation::new(SIDE_SIZE, Directions::down_and_sideways());
+        for index in random_order(SIDE_SIZE * SIDE_SIZE, &mut rng).into_iter().take(60) {
+            percolation.open(index / SIDE_SIZE + 1, index % SIDE_SIZE + 1);
+        }
+
+        let mut reachable = vec![false; SIDE_SIZE * SIDE_SIZE];
+        let mut stack = (1..SIDE_SIZE + 1).filter(|&col| percolation.is_open(1, col))
+            .map(|col| (1, col))
+            .collect::<Vec<_>>();
+        while let Some((row, col)) = stack.pop() {
+            let index = (row - 1) * SIDE_SIZE + col - 1;
+            if reachable[index] {
+                continue;
+            }
+            reachable[index] = true;
+            let mut next = Vec::new();
+            if row < SIDE_SIZE {
+                next.push((row + 1, col));
+            }
+            if col > 1 {
+                next.push((row, col - 1));
+            }
+            if col < SIDE_SIZE {
+                next.push((row, col + 1));
+            }
+            for (r, c) in next {
+                if percolation.is_open(r, c) {
+                    stack.push((r, c));
+                }
+            }
+        }
+        for row in 1..SIDE_SIZE + 1 {
+            for col in 1..SIDE_SIZE + 1 {
+                expect!(percolation.is_full(row, col)).to(be_equal_to(reachable[(row - 1) * SIDE_SIZE + col - 1]));
+            }
+        }
+    }
+
+    it "should estimate a directed threshold above the undirected one" {
+        let mut rng = XorShiftRng::from_seed([29, 31, 37, 41]);
+        let stats = PercolationStats::directed(40, 50, Directions::down_and_right(), &mut rng);
+
+        expect!(stats.mean()).to(be_greater_than(0.65));
+        expect!(stats.mean()).to(be_less_than(0.76));
+    }
+}
@@ -0,0 +1,57 @@
+pub use algorithms::percolation::{Percolation, PercolationError};
+pub use algorithms::percolation::factory::Implementation;
+
+pub use expectest::prelude::{be_true, be_false, be_err, be_equal_to};
+
+describe! implementation_factory_tests {
+
+    it "should parse implementation names" {
+        expect!("brute-force".parse::<Implementation>()).to(be_equal_to(Ok(Implementation::BruteForce)));
+        expect!("union-find".parse::<Implementation>()).to(be_equal_to(Ok(Implementation::UnionFind)));
+        expect!("hack-union-find".parse::<Implementation>()).to(be_equal_to(Ok(Implementation::HackUnionFind)));
+    }
+
+    it "should parse names regardless of case and separator" {
+        expect!("Union_Find".parse::<Implementation>()).to(be_equal_to(Ok(Implementation::UnionFind)));
+    }
+
+    it "should reject unknown names" {
+        expect!("quick-find".parse::<Implementation>()).to(be_err());
+    }
+
+    it "should display names that parse back" {
+        for &implementation in Implementation::all().iter() {
+            expect!(implementation.to_string().parse::<Implementation>()).to(be_equal_to(Ok(implementation)));
+        }
+    }
+
+    it "should create every implementation behind the trait" {
+        for &implementation in Implementation::all().iter() {
+            let mut percolation = implementation.create(3);
+            for row in 1..4 {
+                percolation.open(row, 2);
+            }
+
+            expect!(percolation.side_size()).to(be_equal_to(3));
+            expect!(percolation.number_of_open_sites()).to(be_equal_to(3));
+            expect!(percolation.percolates()).to(be_true());
+        }
+    }
+
+    it "should not count sites opened twice" {
+        for &implementation in Implementation::all().iter() {
+            let mut percolation = implementation.create(5);
+            percolation.open(2, 5);
+            percolation.open(2, 5);
+            percolation.open(5, 5);
+
+            expect!(percolation.number_of_open_sites()).to(be_equal_to(2));
+            expect!(percolation.percolates()).to(be_false());
+        }
+    }
+
+    it "should report empty grid" {
+        expect!(Implementation::UnionFind.try_create(0).err()).to(be_equal_to(Some(PercolationError::EmptyGrid)));
+        expect!(Implementation::BruteForce.try_create(2).is_ok()).to(be_true());
+    }
+}
@@ -0,0 +1,163 @@
+pub use rand::{SeedableRng, XorShiftRng};
+
+pub use algorithms::percolation::{Percolation, PercolationError, UnionFindPercolation};
+pub use algorithms::percolation::bond::BondPercolation;
+pub use algorithms::percolation::graph::{GraphPercolation, Model, square_lattice};
+pub use algorithms::percolation::stats::random_order;
+
+pub use expectest::prelude::{be_true, be_false, be_err, be_equal_to};
+
+describe! graph_percolation_tests {
+
+    describe! site_model {
+
+        before_each {
+            let edges = [(0, 1), (1, 2), (2, 3), (1, 4), (4, 3)];
+            let mut percolation = GraphPercolation::new(5, &edges, &[0], &[3], Model::Site);
+        }
+
+        it "should fill opened source" {
+            percolation.open_site(0);
+
+            expect!(percolation.is_full(0)).to(be_true());
+        }
+
+        it "should not fill site disconnected from sources" {
+            percolation.open_site(2);
+
+            expect!(percolation.is_full(2)).to(be_false());
+        }
+
+        it "should fill sites connected through open sites" {
+            percolation.open_site(2);
+            percolation.open_site(1);
+            percolation.open_site(0);
+
+            expect!(percolation.is_full(2)).to(be_true());
+            expect!(percolation.percolates()).to(be_false());
+        }
+
+        it "should percolate when a sink gets full" {
+            percolation.open_site(0);
+            percolation.open_site(1);
+            percolation.open_site(4);
+            percolation.open_site(3);
+
+            expect!(percolation.percolates()).to(be_true());
+            expect!(percolation.is_full(2)).to(be_false());
+        }
+    }
+
+    describe! bond_model {
+
+        before_each {
+            let edges = [(0, 1), (1, 2), (0, 2), (2, 3)];
+            let mut percolation = GraphPercolation::new(4, &edges, &[0], &[3], Model::Bond);
+        }
+
+        it "should open every site" {
+            expect!(percolation.is_site_open(3)).to(be_true());
+            expect!(percolation.is_full(0)).to(be_true());
+            expect!(percolation.is_full(1)).to(be_false());
+        }
+
+        it "should fill through open bonds" {
+            percolation.open_bond(2);
+            percolation.open_bond(1);
+
+            expect!(percolation.is_full(1)).to(be_true());
+            expect!(percolation.percolates()).to(be_false());
+        }
+
+        it "should percolate when the bonds connect source and sink" {
+            percolation.open_bond(3);
+            percolation.open_bond(2);
+
+            expect!(percolation.percolates()).to(be_true());
+        }
+    }
+
+    it "should transmit only through open sites and open bonds" {
+        let mut percolation = GraphPercolation::new(3, &[(0, 1), (1, 2)], &[0], &[2], Model::SiteBond);
+        percolation.open_site(0);
+        percolation.open_site(1);
+        percolation.open_site(2);
+
+        expect!(percolation.is_full(1)).to(be_false());
+
+        percolation.open_bond(0);
+        percolation.open_bond(1);
+
+        expect!(percolation.percolates()).to(be_true());
+    }
+
+    it "should reject edges to unknown vertices" {
+        expect!(GraphPercolation::try_new(2, &[(0, 2)], &[0], &[1], Model::Site).err())
+            .to(be_equal_to(Some(PercolationError::NoSuchVertex { vertex: 2, vertices: 2 })));
+    }
+
+    it "should reject unknown edges and vertices after construction" {
+        let mut percolation = GraphPercolation::new(3, &[(0, 1), (1, 2)], &[0], &[2], Model::SiteBond);
+
+        expect!(percolation.try_open_bond(2).err())
+            .to(be_equal_to(Some(PercolationError::NoSuchEdge { edge: 2, edges: 2 })));
+        expect!(percolation.try_is_bond_open(5)).to(be_err());
+        expect!(percolation.try_is_site_open(3).err())
+            .to(be_equal_to(Some(PercolationError::NoSuchVertex { vertex: 3, vertices: 3 })));
+        expect!(percolation.try_is_full(3)).to(be_err());
+    }
+
+    it "should open bonds and report fullness of known vertices" {
+        let mut percolation = GraphPercolation::new(2, &[(0, 1)], &[0], &[1], Model::Bond);
+
+        expect!(percolation.try_open_bond(0).is_ok()).to(be_true());
+        expect!(percolation.try_is_full(1)).to(be_equal_to(Ok(true)));
+        expect!(percolation.try_is_site_open(1)).to(be_equal_to(Ok(true)));
+    }
+
+    it "should reject unknown sinks" {
+        expect!(GraphPercolation::try_new(2, &[(0, 1)], &[0], &[5], Model::Site).err())
+            .to(be_equal_to(Some(PercolationError::NoSuchVertex { vertex: 5, vertices: 2 })));
+    }
+
+    it "should agree with grid percolation on a square lattice" {
+        const SIDE_SIZE: usize = 10;
+        let mut rng = XorShiftRng::from_seed([2, 4, 6, 8]);
+        let sources = (0..SIDE_SIZE).collect::<Vec<_>>();
+        let sinks = (SIDE_SIZE * (SIDE_SIZE - 1)..SIDE_SIZE * SIDE_SIZE).collect::<Vec<_>>();
+        let mut graph = GraphPercolation::new(SIDE_SIZE * SIDE_SIZE, &square_lattice(SIDE_SIZE), &sources, &sinks,
+            Model::Site);
+        let mut grid = UnionFindPercolation::new(SIDE_SIZE);
+        for index in random_order(SIDE_SIZE * SIDE_SIZE, &mut rng) {
+            graph.open_site(index);
+            grid.open(index / SIDE_SIZE + 1, index % SIDE_SIZE + 1);
+
+            expect!(graph.percolates()).to(be_equal_to(grid.percolates()));
+            for vertex in 0..SIDE_SIZE * SIDE_SIZE {
+                expect!(graph.is_full(vertex)).to(be_equal_to(grid.is_full(vertex / SIDE_SIZE + 1, vertex % SIDE_SIZE + 1)));
+            }
+        }
+    }
+
+    it "should agree with bond percolation on a square lattice" {
+        const SIDE_SIZE: usize = 8;
+        let mut rng = XorShiftRng::from_seed([1, 3, 5, 7]);
+        let edges = square_lattice(SIDE_SIZE);
+        let sources = (0..SIDE_SIZE).collect::<Vec<_>>();
+        let sinks = (SIDE_SIZE * (SIDE_SIZE - 1)..SIDE_SIZE * SIDE_SIZE).collect::<Vec<_>>();
+        let mut graph = GraphPercolation::new(SIDE_SIZE * SIDE_SIZE, &edges, &sources, &sinks, Model::Bond);
+        let mut grid = BondPercolation::new(SIDE_SIZE);
+        let site = |index: usize| (index / SIDE_SIZE + 1, index % SIDE_SIZE + 1);
+        for edge in random_order(edges.len(), &mut rng) {
+            let (p, q) = edges[edge];
+            graph.open_bond(edge);
+            grid.open_bond(site(p), site(q));
+
+            expect!(graph.percolates()).to(be_equal_to(grid.percolates()));
+            for vertex in 0..SIDE_SIZE * SIDE_SIZE {
+                let (row, col) = site(vertex);
+                expect!(graph.is_full(vertex)).to(be_equal_to(grid.is_full(row, col)));
+            }
+        }
+    }
+}
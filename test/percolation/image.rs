@@ -0,0 +1,196 @@
+pub use std::io::ErrorKind;
+
+pub use algorithms::percolation::{Percolation, PercolationError, UnionFindPercolation, HackUnionFindPercolation};
+pub use algorithms::percolation::clusters::ClusterStats;
+pub use algorithms::percolation::image::Mask;
+pub use algorithms::percolation::render::write_pgm;
+
+pub use expectest::prelude::{be_true, be_false, be_err, be_equal_to};
+
+describe! image_mask_tests {
+
+    it "should read ascii bitmap with white pixels open" {
+        let image = b"P1\n# sample\n3 2\n0 1 0\n1 1 0\n";
+        let mask = Mask::read(&mut &image[..], 0.5).unwrap();
+
+        expect!(mask.width()).to(be_equal_to(3));
+        expect!(mask.height()).to(be_equal_to(2));
+        expect!(mask.is_open(1, 1)).to(be_true());
+        expect!(mask.is_open(1, 2)).to(be_false());
+        expect!(mask.number_of_open_sites()).to(be_equal_to(3));
+    }
+
+    it "should read ascii bitmap without separators between pixels" {
+        let image = b"P1 3 2 010\n110";
+        let mask = Mask::read(&mut &image[..], 0.5).unwrap();
+
+        expect!(mask.number_of_open_sites()).to(be_equal_to(3));
+        expect!(mask.is_open(2, 3)).to(be_true());
+    }
+
+    it "should read binary bitmap with padded rows" {
+        let image = b"P4\n10 2\n\x40\x00\xff\xc0";
+        let mask = Mask::read(&mut &image[..], 0.5).unwrap();
+
+        expect!(mask.is_open(1, 1)).to(be_true());
+        expect!(mask.is_open(1, 2)).to(be_false());
+        expect!(mask.is_open(1, 10)).to(be_true());
+        expect!(mask.number_of_open_sites()).to(be_equal_to(9));
+    }
+
+    it "should threshold ascii graymap" {
+        let image = b"P2\n2 2\n15\n0 7\n8 15\n";
+        let mask = Mask::read(&mut &image[..], 0.5).unwrap();
+
+        expect!(mask.is_open(1, 2)).to(be_false());
+        expect!(mask.is_open(2, 1)).to(be_true());
+        expect!(mask.is_open(2, 2)).to(be_true());
+    }
+
+    it "should threshold binary graymap with two bytes per pixel" {
+        let image = b"P5 2 1 1000 \x01\xf4\x01\xf3";
+        let mask = Mask::read(&mut &image[..], 0.5).unwrap();
+
+        expect!(mask.is_open(1, 1)).to(be_true());
+        expect!(mask.is_open(1, 2)).to(be_false());
+    }
+
+    it "should read back a rendered graymap" {
+        let mut original = UnionFindPercolation::new(4);
+        original.open(1, 2);
+        original.open(2, 2);
+        original.open(4, 4);
+        let mut image = Vec::new();
+        write_pgm(&original, 1, &mut image).unwrap();
+
+        let mask = Mask::read(&mut image.as_slice(), 0.5).unwrap();
+        let restored = mask.to_percolation(UnionFindPercolation::new).unwrap();
+
+        for row in 1..5 {
+            for col in 1..5 {
+                expect!(restored.site(row, col)).to(be_equal_to(original.site(row, col)));
+            }
+        }
+    }
+
+    it "should reject truncated image" {
+        let error = Mask::read(&mut &b"P5 2 2 255 \x00\x00\x00"[..], 0.5).map(|_| ()).unwrap_err();
+
+        expect!(error.kind()).to(be_equal_to(ErrorKind::UnexpectedEof));
+    }
+
+    it "should reject unsupported format" {
+        expect!(Mask::read(&mut &b"P6 1 1 255 \x00\x00\x00"[..], 0.5).map(|_| ())).to(be_err());
+    }
+
+    describe! tall_sample {
+
+        before_each {
+            let image = b"P1\n2 4\n01\n01\n01\n10\n";
+            let mask = Mask::read(&mut &image[..], 0.5).unwrap();
+        }
+
+        it "should pad a narrow image with blocked columns" {
+            let percolation = mask.to_percolation(HackUnionFindPercolation::new).unwrap();
+
+            expect!(percolation.side_size()).to(be_equal_to(4));
+            expect!(percolation.number_of_open_sites()).to(be_equal_to(4));
+            expect!(percolation.is_open(1, 3)).to(be_false());
+            expect!(percolation.percolates()).to(be_false());
+        }
+
+        it "should compute the percolating path of the sample" {
+            let mut percolation = mask.to_percolation(UnionFindPercolation::new).unwrap();
+            percolation.open(3, 2);
+
+            expect!(percolation.percolates()).to(be_true());
+            expect!(percolation.percolating_path()).to(be_equal_to(Some(vec![(1, 1), (2, 1), (3, 1), (3, 2), (4, 2)])));
+        }
+
+        it "should compute cluster stats of the sample" {
+            let percolation = mask.to_percolation(UnionFindPercolation::new).unwrap();
+            let stats = ClusterStats::from_percolation(&percolation);
+
+            expect!(stats.number_of_clusters()).to(be_equal_to(2));
+            expect!(stats.largest_cluster_size()).to(be_equal_to(3));
+        }
+    }
+
+    it "should reject image wider than high" {
+        let mask = Mask::read(&mut &b"P1 3 2 000 000"[..], 0.5).unwrap();
+
+        expect!(mask.to_percolation(UnionFindPercolation::new).err())
+            .to(be_equal_to(Some(PercolationError::MaskMismatch { width: 3, height: 2, side_size: 2 })));
+    }
+
+    describe! wide_sample {
+
+        before_each {
+            let image = b"P1 4 2 1001 1100";
+            let mask = Mask::read(&mut &image[..], 0.5).unwrap();
+        }
+
+        it "should place a wide image in a grid as big as its width" {
+            let percolation = mask.to_sample();
+
+            expect!(percolation.side_size()).to(be_equal_to(4));
+            expect!(percolation.number_of_open_sites()).to(be_equal_to(4));
+            expect!(percolation.is_open(3, 3)).to(be_false());
+        }
+
+        it "should percolate from the top to the bottom row of the image" {
+            let percolation = mask.to_sample();
+
+            expect!(percolation.percolates()).to(be_true());
+            expect!(percolation.is_full(2, 4)).to(be_true());
+            expect!(percolation.percolating_path()).to(be_equal_to(Some(vec![(1, 3), (2, 3)])));
+        }
+
+        it "should not percolate sideways" {
+            let mask = Mask::read(&mut &b"P1 3 2 000 111"[..], 0.5).unwrap();
+            let percolation = mask.to_sample();
+
+            expect!(percolation.percolates()).to(be_false());
+        }
+    }
+
+    it "should percolate a tall image sample like a grid of its height" {
+        let mask = Mask::read(&mut &b"P1 2 3 10 10 10"[..], 0.5).unwrap();
+
+        expect!(mask.to_sample().percolates()).to(be_true());
+        expect!(mask.to_percolation(UnionFindPercolation::new).unwrap().percolates()).to(be_true());
+    }
+
+    it "should reject header whose pixels overflow" {
+        let image = format!("P4 {} {} ", ::std::usize::MAX, 16);
+        let error = Mask::read(&mut image.as_bytes(), 0.5).map(|_| ()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::InvalidData));
+
+        let image = format!("P5 {} 1 65535 ", ::std::usize::MAX / 2 + 1);
+        let error = Mask::read(&mut image.as_bytes(), 0.5).map(|_| ()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::InvalidData));
+    }
+
+    it "should reject huge bitmap without pixels" {
+        let image = format!("P1 {} 1 0", 100000000);
+        let error = Mask::read(&mut image.as_bytes(), 0.5).map(|_| ()).unwrap_err();
+
+        expect!(error.kind()).to(be_equal_to(ErrorKind::UnexpectedEof));
+    }
+
+    it "should reject threshold out of range" {
+        let image = b"P1 1 1 0";
+
+        expect!(Mask::read(&mut &image[..], ::std::f64::NAN).map(|_| ())).to(be_err());
+        expect!(Mask::read(&mut &image[..], -0.1).map(|_| ())).to(be_err());
+        expect!(Mask::read(&mut &image[..], 1.1).map(|_| ())).to(be_err());
+        expect!(Mask::read(&mut &image[..], 1.0).is_ok()).to(be_true());
+    }
+
+    it "should reject grid of another height" {
+        let mask = Mask::read(&mut &b"P1 2 2 00 00"[..], 0.5).unwrap();
+        let mut percolation = UnionFindPercolation::new(3);
+
+        expect!(mask.apply(&mut percolation)).to(be_err());
+    }
+}
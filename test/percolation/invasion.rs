@@ -0,0 +1,116 @@
+pub use algorithms::percolation::{Percolation, UnionFindPercolation, BruteForcePercolation};
+pub use algorithms::percolation::invasion::{InvasionPercolation, Trapping};
+
+pub use rand::{SeedableRng, XorShiftRng};
+
+pub use expectest::prelude::{be_true, be_false, be_equal_to};
+
+pub fn strengths() -> Vec<f64> {
+    vec![
+        0.01, 0.02, 0.03, 0.90,
+        0.04, 0.99, 0.05, 0.91,
+        0.06, 0.07, 0.08, 0.92,
+        0.95, 0.96, 0.97, 0.10
+    ]
+}
+
+pub fn trapped_by_search(invasion: &InvasionPercolation<UnionFindPercolation>) -> bool {
+    let side_size = invasion.percolation().side_size();
+    let defended = |row: usize, col: usize| !invasion.percolation().is_open(row, col) && !invasion.is_trapped(row, col);
+    let mut reaches_bottom = vec![false; side_size * side_size];
+    let mut stack = (1..side_size + 1).filter(|&col| defended(side_size, col)).map(|col| (side_size, col)).collect::<Vec<_>>();
+    while let Some((row, col)) = stack.pop() {
+        let index = (row - 1) * side_size + col - 1;
+        if reaches_bottom[index] {
+            continue;
+        }
+        reaches_bottom[index] = true;
+        let neighbors = [(row - 1, col), (row + 1, col), (row, col - 1), (row, col + 1)];
+        for &(r, c) in neighbors.iter() {
+            if r >= 1 && r <= side_size && c >= 1 && c <= side_size && defended(r, c) {
+                stack.push((r, c));
+            }
+        }
+    }
+    (1..side_size + 1).all(|row| (1..side_size + 1).all(|col| {
+        let index = (row - 1) * side_size + col - 1;
+        invasion.percolation().is_open(row, col) || reaches_bottom[index] != invasion.is_trapped(row, col)
+    }))
+}
+
+describe! invasion_percolation_tests {
+
+    it "should invade the weakest perimeter site first" {
+        let mut invasion = InvasionPercolation::with_strengths(UnionFindPercolation::new(4), strengths(), Trapping::Disabled);
+
+        invasion.step();
+        invasion.step();
+        invasion.step();
+        invasion.step();
+
+        expect!(invasion.invaded().to_vec()).to(be_equal_to(vec![(1, 1), (1, 2), (1, 3), (2, 1)]));
+    }
+
+    it "should stop at breakthrough to the bottom row" {
+        let mut invasion = InvasionPercolation::with_strengths(UnionFindPercolation::new(4), strengths(), Trapping::Disabled);
+
+        let invaded = invasion.run();
+
+        expect!(invasion.percolation().percolates()).to(be_true());
+        expect!(invaded).to(be_equal_to(invasion.invaded().len()));
+        expect!(invasion.invaded()[invaded - 1]).to(be_equal_to((4, 4)));
+        expect!(invasion.percolation().is_open(2, 2)).to(be_false());
+    }
+
+    it "should trap defender surrounded by invaded sites" {
+        let mut invasion = InvasionPercolation::with_strengths(UnionFindPercolation::new(4), strengths(), Trapping::Enabled);
+
+        invasion.run();
+
+        expect!(invasion.is_trapped(2, 2)).to(be_true());
+        expect!(invasion.is_trapped(4, 1)).to(be_false());
+    }
+
+    it "should not trap defender without trapping rules" {
+        let mut invasion = InvasionPercolation::with_strengths(UnionFindPercolation::new(4), strengths(), Trapping::Disabled);
+
+        invasion.run();
+
+        expect!(invasion.is_trapped(2, 2)).to(be_false());
+    }
+
+    it "should trap exactly the defenders cut off from the bottom row" {
+        let mut rng = XorShiftRng::from_seed([2, 7, 1, 8]);
+        let mut invasion = InvasionPercolation::new(UnionFindPercolation::new(20), Trapping::Enabled, &mut rng);
+
+        while invasion.step().is_some() {
+            expect!(trapped_by_search(&invasion)).to(be_true());
+        }
+    }
+
+    it "should never invade trapped defenders" {
+        let mut rng = XorShiftRng::from_seed([2, 7, 1, 8]);
+        let mut invasion = InvasionPercolation::new(UnionFindPercolation::new(20), Trapping::Enabled, &mut rng);
+
+        invasion.run();
+
+        expect!(invasion.invaded().iter().any(|&(row, col)| invasion.is_trapped(row, col))).to(be_false());
+    }
+
+    it "should invade a connected cluster of full sites" {
+        let mut rng = XorShiftRng::from_seed([3, 1, 4, 1]);
+        let mut invasion = InvasionPercolation::new(BruteForcePercolation::new(30), Trapping::Enabled, &mut rng);
+
+        invasion.run();
+
+        expect!(invasion.percolation().percolates()).to(be_true());
+        expect!(invasion.invaded().iter().all(|&(row, col)| invasion.percolation().is_full(row, col))).to(be_true());
+    }
+
+    failing "should refuse a percolation with open sites" {
+        let mut percolation = UnionFindPercolation::new(4);
+        percolation.open(2, 2);
+
+        InvasionPercolation::with_strengths(percolation, strengths(), Trapping::Disabled);
+    }
+}
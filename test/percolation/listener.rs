@@ -0,0 +1,140 @@
+pub use algorithms::percolation::{Percolation, BruteForcePercolation, UnionFindPercolation, HackUnionFindPercolation};
+pub use algorithms::percolation::listener::PercolationListener;
+
+pub use expectest::prelude::be_equal_to;
+
+#[derive(Debug, PartialEq)]
+pub enum Event {
+    Open(usize, usize),
+    Close(usize, usize),
+    Full(usize, usize),
+    Drain(usize, usize),
+    Percolate
+}
+
+pub struct Recorder {
+    events: Vec<Event>
+}
+
+impl Recorder {
+
+    pub fn new() -> Recorder {
+        Recorder {
+            events: Vec::new()
+        }
+    }
+}
+
+impl PercolationListener for Recorder {
+
+    fn on_open(&mut self, row: usize, col: usize) {
+        self.events.push(Event::Open(row, col));
+    }
+
+    fn on_close(&mut self, row: usize, col: usize) {
+        self.events.push(Event::Close(row, col));
+    }
+
+    fn on_full(&mut self, row: usize, col: usize) {
+        self.events.push(Event::Full(row, col));
+    }
+
+    fn on_drain(&mut self, row: usize, col: usize) {
+        self.events.push(Event::Drain(row, col));
+    }
+
+    fn on_percolate(&mut self) {
+        self.events.push(Event::Percolate);
+    }
+}
+
+pub fn open_cascade(percolation: &mut Percolation) {
+    percolation.open(3, 1);
+    percolation.open(2, 1);
+    percolation.open(1, 1);
+    percolation.open(3, 2);
+}
+
+pub fn expected_events() -> Vec<Event> {
+    vec![
+        Event::Open(3, 1),
+        Event::Open(2, 1),
+        Event::Open(1, 1),
+        Event::Full(1, 1),
+        Event::Full(2, 1),
+        Event::Full(3, 1),
+        Event::Percolate,
+        Event::Open(3, 2),
+        Event::Full(3, 2)
+    ]
+}
+
+describe! listener_tests {
+
+    it "should notify about brute force percolation events" {
+        let mut percolation = BruteForcePercolation::with_listener(3, Recorder::new());
+
+        open_cascade(&mut percolation);
+
+        expect!(&percolation.listener().events).to(be_equal_to(&expected_events()));
+    }
+
+    it "should notify about union find percolation events" {
+        let mut percolation = UnionFindPercolation::with_listener(3, Recorder::new());
+
+        open_cascade(&mut percolation);
+
+        expect!(&percolation.listener().events).to(be_equal_to(&expected_events()));
+    }
+
+    it "should notify about hack union find percolation events" {
+        let mut percolation = HackUnionFindPercolation::with_listener(3, Recorder::new());
+
+        open_cascade(&mut percolation);
+
+        expect!(&percolation.listener().events).to(be_equal_to(&expected_events()));
+    }
+
+    it "should not notify about already open sites" {
+        let mut percolation = UnionFindPercolation::with_listener(3, Recorder::new());
+
+        percolation.open(2, 2);
+        percolation.open(2, 2);
+
+        expect!(&percolation.listener().events).to(be_equal_to(&vec![Event::Open(2, 2)]));
+    }
+
+    it "should notify about sites drained by closing" {
+        let mut percolation = BruteForcePercolation::with_listener(3, Recorder::new());
+        open_cascade(&mut percolation);
+        percolation.open(1, 2);
+        percolation.open(2, 2);
+        percolation.listener_mut().events.clear();
+
+        percolation.close(2, 1);
+
+        expect!(&percolation.listener().events)
+            .to(be_equal_to(&vec![Event::Close(2, 1), Event::Drain(2, 1)]));
+    }
+
+    it "should notify about every site cut off from the top" {
+        let mut percolation = BruteForcePercolation::with_listener(3, Recorder::new());
+        open_cascade(&mut percolation);
+        percolation.listener_mut().events.clear();
+
+        percolation.close(1, 1);
+
+        let mut events = percolation.listener_mut().events.drain(..).collect::<Vec<_>>();
+        expect!(events.remove(0)).to(be_equal_to(Event::Close(1, 1)));
+        events.sort_by_key(|event| format!("{:?}", event));
+        expect!(events).to(be_equal_to(vec![Event::Drain(1, 1), Event::Drain(2, 1), Event::Drain(3, 1), Event::Drain(3, 2)]));
+    }
+
+    it "should not notify about closing blocked sites" {
+        let mut percolation = BruteForcePercolation::with_listener(3, Recorder::new());
+
+        percolation.close(2, 2);
+
+        expect!(&percolation.listener().events).to(be_equal_to(&vec![]));
+    }
+}
@@ -1,6 +1,31 @@
-pub use algorithms::percolation::{Percolation, BruteForcePercolation, UnionFindPercolation, HackUnionFindPercolation};
-
-pub use expectest::prelude::{be_true, be_false};
+mod bond;
+mod clusters;
+mod cubic;
+mod directed;
+mod factory;
+mod graph;
+mod image;
+mod invasion;
+mod listener;
+mod newman_ziff;
+mod path;
+mod random_graph;
+mod render;
+mod replay;
+mod sites;
+mod snapshot;
+mod source_sink;
+mod spiral;
+mod stats;
+mod stats_config;
+mod undo;
+
+pub use rand::{Rng, SeedableRng, XorShiftRng};
+
+pub use algorithms::percolation::{Percolation, PercolationError, BruteForcePercolation, UnionFindPercolation, HackUnionFindPercolation};
+pub use algorithms::percolation::stats::random_order;
+
+pub use expectest::prelude::{be_true, be_false, be_ok, be_err, be_equal_to};
 
 pub fn open_column_till_row(percolation: &mut Percolation, row: usize, col: usize) {
     for r in 1..row + 1 {
@@ -17,6 +42,22 @@ describe! percolation_tests {
             let mut percolation = BruteForcePercolation::new(SIDE_SIZE);
         }
 
+        it "should not connect an opened cell to closed neighbors" {
+            percolation.open(1, 1);
+            percolation.open(3, 1);
+
+            expect!(percolation.is_full(3, 1)).to(be_false());
+        }
+
+        it "should fill only through open cells above" {
+            percolation.open(2, 1);
+            percolation.open(2, 2);
+            percolation.open(1, 1);
+
+            expect!(percolation.is_full(2, 2)).to(be_true());
+            expect!(percolation.is_full(1, 2)).to(be_false());
+        }
+
         it "should open a cell" {
             percolation.open(1, 1);
 
@@ -105,6 +146,22 @@ describe! percolation_tests {
             let mut percolation = UnionFindPercolation::new(SIDE_SIZE);
         }
 
+        it "should not connect an opened cell to closed neighbors" {
+            percolation.open(1, 1);
+            percolation.open(3, 1);
+
+            expect!(percolation.is_full(3, 1)).to(be_false());
+        }
+
+        it "should fill only through open cells above" {
+            percolation.open(2, 1);
+            percolation.open(2, 2);
+            percolation.open(1, 1);
+
+            expect!(percolation.is_full(2, 2)).to(be_true());
+            expect!(percolation.is_full(1, 2)).to(be_false());
+        }
+
         it "should open a cell" {
             percolation.open(1, 1);
 
@@ -184,6 +241,22 @@ describe! percolation_tests {
             let mut percolation = HackUnionFindPercolation::new(SIDE_SIZE);
         }
 
+        it "should not connect an opened cell to closed neighbors" {
+            percolation.open(1, 1);
+            percolation.open(3, 1);
+
+            expect!(percolation.is_full(3, 1)).to(be_false());
+        }
+
+        it "should fill only through open cells above" {
+            percolation.open(2, 1);
+            percolation.open(2, 2);
+            percolation.open(1, 1);
+
+            expect!(percolation.is_full(2, 2)).to(be_true());
+            expect!(percolation.is_full(1, 2)).to(be_false());
+        }
+
         it "should open a cell" {
             percolation.open(1, 1);
 
@@ -255,4 +328,142 @@ describe! percolation_tests {
             expect!(percolation.is_full(SIDE_SIZE, SIDE_SIZE)).to(be_false());
         }
     }
+
+    describe! closing {
+
+        before_each {
+            const SIDE_SIZE: usize = 5;
+            let mut percolation = BruteForcePercolation::new(SIDE_SIZE);
+        }
+
+        it "should block closed site" {
+            percolation.open(2, 2);
+            percolation.close(2, 2);
+
+            expect!(percolation.is_open(2, 2)).to(be_false());
+            expect!(percolation.is_full(2, 2)).to(be_false());
+        }
+
+        it "should drain sites cut off from the top" {
+            open_column_till_row(&mut percolation, 3, 1);
+            percolation.close(2, 1);
+
+            expect!(percolation.is_full(1, 1)).to(be_true());
+            expect!(percolation.is_full(3, 1)).to(be_false());
+            expect!(percolation.is_open(3, 1)).to(be_true());
+        }
+
+        it "should keep sites that are still reachable through another way" {
+            open_column_till_row(&mut percolation, 3, 1);
+            open_column_till_row(&mut percolation, 3, 2);
+            percolation.close(2, 1);
+
+            expect!(percolation.is_full(3, 1)).to(be_true());
+        }
+
+        it "should stop percolating when the only path is closed" {
+            open_column_till_row(&mut percolation, SIDE_SIZE, 3);
+            percolation.close(4, 3);
+
+            expect!(percolation.percolates()).to(be_false());
+        }
+
+        it "should percolate again when closed site is reopened" {
+            open_column_till_row(&mut percolation, SIDE_SIZE, 3);
+            percolation.close(4, 3);
+            percolation.open(4, 3);
+
+            expect!(percolation.percolates()).to(be_true());
+        }
+
+        it "should reject out of bounds closing" {
+            expect!(percolation.try_close(SIDE_SIZE + 1, 1)).to(be_err());
+        }
+
+        it "should match a percolation rebuilt from scratch after random opening and closing" {
+            const RANDOM_SIDE_SIZE: usize = 12;
+            let mut rng = XorShiftRng::from_seed([5, 8, 13, 21]);
+            let mut percolation = BruteForcePercolation::new(RANDOM_SIDE_SIZE);
+            let mut open = vec![false; RANDOM_SIDE_SIZE * RANDOM_SIDE_SIZE];
+            for _ in 0..20 {
+                for index in random_order(open.len(), &mut rng).into_iter().take(40) {
+                    let (row, col) = (index / RANDOM_SIDE_SIZE + 1, index % RANDOM_SIDE_SIZE + 1);
+                    if rng.gen::<f64>() < 0.6 {
+                        percolation.open(row, col);
+                        open[index] = true;
+                    }
+                    else {
+                        percolation.close(row, col);
+                        open[index] = false;
+                    }
+                }
+
+                let mut rebuilt = BruteForcePercolation::new(RANDOM_SIDE_SIZE);
+                for index in (0..open.len()).filter(|&index| open[index]) {
+                    rebuilt.open(index / RANDOM_SIDE_SIZE + 1, index % RANDOM_SIDE_SIZE + 1);
+                }
+                expect!(percolation.percolates()).to(be_equal_to(rebuilt.percolates()));
+                for row in 1..RANDOM_SIDE_SIZE + 1 {
+                    for col in 1..RANDOM_SIDE_SIZE + 1 {
+                        expect!(percolation.site(row, col)).to(be_equal_to(rebuilt.site(row, col)));
+                    }
+                }
+            }
+        }
+    }
+
+    describe! checked_access {
+
+        before_each {
+            const SIDE_SIZE: usize = 5;
+            let mut percolation = UnionFindPercolation::new(SIDE_SIZE);
+        }
+
+        it "should open a cell within bounds" {
+            expect!(percolation.try_open(SIDE_SIZE, SIDE_SIZE)).to(be_ok());
+            expect!(percolation.is_open(SIDE_SIZE, SIDE_SIZE)).to(be_true());
+        }
+
+        it "should reject zero row" {
+            expect!(percolation.try_open(0, 1))
+                .to(be_equal_to(Err(PercolationError::OutOfBounds { row: 0, col: 1, side_size: SIDE_SIZE })));
+        }
+
+        it "should reject column past the side size instead of aliasing the next row" {
+            expect!(percolation.try_open(1, SIDE_SIZE + 1)).to(be_err());
+            expect!(percolation.is_open(2, 1)).to(be_false());
+        }
+
+        it "should reject out of bounds queries" {
+            expect!(percolation.try_is_open(SIDE_SIZE + 1, 1)).to(be_err());
+            expect!(percolation.try_is_full(1, 0)).to(be_err());
+        }
+
+        it "should answer in bounds queries" {
+            percolation.open(1, 1);
+
+            expect!(percolation.try_is_full(1, 1)).to(be_equal_to(Ok(true)));
+        }
+
+        it "should reject out of bounds sites for every implementation" {
+            let mut brute_force = BruteForcePercolation::new(SIDE_SIZE);
+            let mut hack = HackUnionFindPercolation::new(SIDE_SIZE);
+
+            expect!(brute_force.try_open(SIDE_SIZE + 1, 1)).to(be_err());
+            expect!(hack.try_open(1, SIDE_SIZE + 1)).to(be_err());
+        }
+
+        it "should describe valid range of out of bounds site" {
+            let error = PercolationError::OutOfBounds { row: 6, col: 1, side_size: 5 };
+
+            expect!(error.to_string())
+                .to(be_equal_to("site (6, 1) is out of bounds, row and column must be between 1 and 5".to_owned()));
+        }
+
+        it "should reject empty grid" {
+            expect!(BruteForcePercolation::try_new(0).err()).to(be_equal_to(Some(PercolationError::EmptyGrid)));
+            expect!(UnionFindPercolation::try_new(0).err()).to(be_equal_to(Some(PercolationError::EmptyGrid)));
+            expect!(HackUnionFindPercolation::try_new(0).err()).to(be_equal_to(Some(PercolationError::EmptyGrid)));
+        }
+    }
 }
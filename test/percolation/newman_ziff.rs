@@ -0,0 +1,90 @@
+pub use algorithms::percolation::PercolationError;
+pub use algorithms::percolation::newman_ziff::{NewmanZiff, binomial_weights};
+
+pub use rand::{SeedableRng, XorShiftRng};
+
+pub use expectest::prelude::{be_true, be_equal_to, be_close_to, be_less_than, be_greater_than};
+
+describe! newman_ziff_tests {
+
+    before_each {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+        let newman_ziff = NewmanZiff::run(32, 20, &mut rng);
+    }
+
+    it "should record observables after every open site" {
+        expect!(newman_ziff.spanning_by_open_sites().len()).to(be_equal_to(32 * 32 + 1));
+        expect!(newman_ziff.largest_cluster_by_open_sites().len()).to(be_equal_to(32 * 32 + 1));
+    }
+
+    it "should not percolate without open sites" {
+        expect!(newman_ziff.percolation_probability(0.0)).to(be_close_to(0.0));
+        expect!(newman_ziff.largest_cluster_fraction(0.0)).to(be_close_to(0.0));
+    }
+
+    it "should percolate with all sites open" {
+        expect!(newman_ziff.percolation_probability(1.0)).to(be_close_to(1.0));
+        expect!(newman_ziff.largest_cluster_fraction(1.0)).to(be_close_to(1.0));
+    }
+
+    it "should switch percolation probability around the site threshold" {
+        expect!(newman_ziff.percolation_probability(0.45)).to(be_less_than(0.05));
+        expect!(newman_ziff.percolation_probability(0.75)).to(be_greater_than(0.95));
+    }
+
+    it "should compute the curve at chosen probabilities" {
+        let curve = newman_ziff.curve(&[0.2, 0.5927, 0.9]);
+
+        expect!(curve.len()).to(be_equal_to(3));
+        expect!(curve[1].percolation_probability).to(be_close_to(0.5).delta(0.25));
+        expect!(curve[0].largest_cluster_fraction < curve[1].largest_cluster_fraction).to(be_true());
+        expect!(curve[1].largest_cluster_fraction < curve[2].largest_cluster_fraction).to(be_true());
+    }
+
+    it "should keep clusters touching the top apart" {
+        let newman_ziff = NewmanZiff::from_orders(3, vec![vec![0, 2, 4, 6, 8, 1, 3, 5, 7]]);
+
+        expect!(newman_ziff.largest_cluster_by_open_sites()[2]).to(be_close_to(1.0 / 9.0));
+        expect!(newman_ziff.largest_cluster_by_open_sites()[5]).to(be_close_to(1.0 / 9.0));
+        expect!(newman_ziff.largest_cluster_by_open_sites()[6]).to(be_close_to(4.0 / 9.0));
+    }
+
+    it "should span once a single cluster touches top and bottom" {
+        let newman_ziff = NewmanZiff::from_orders(3, vec![vec![0, 8, 6, 3, 1, 2, 4, 5, 7]]);
+
+        expect!(newman_ziff.spanning_by_open_sites()[3]).to(be_close_to(0.0));
+        expect!(newman_ziff.spanning_by_open_sites()[4]).to(be_close_to(1.0));
+    }
+
+    it "should sum binomial weights to one" {
+        let total = binomial_weights(10000, 0.3).iter().fold(0.0, |sum, w| sum + w);
+
+        expect!(total).to(be_close_to(1.0));
+    }
+
+    it "should compute exact binomial weights" {
+        let weights = binomial_weights(2, 0.5);
+
+        expect!(weights[0]).to(be_close_to(0.25));
+        expect!(weights[1]).to(be_close_to(0.5));
+        expect!(weights[2]).to(be_close_to(0.25));
+    }
+}
+
+describe! newman_ziff_edge_cases_tests {
+
+    it "should reject empty grid" {
+        let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+
+        expect!(NewmanZiff::try_from_orders(0, Vec::new()).err()).to(be_equal_to(Some(PercolationError::EmptyGrid)));
+        expect!(NewmanZiff::try_run(0, 5, &mut rng).err()).to(be_equal_to(Some(PercolationError::EmptyGrid)));
+    }
+
+    it "should give an empty curve without trials" {
+        let newman_ziff = NewmanZiff::from_orders(3, Vec::new());
+
+        expect!(newman_ziff.trials()).to(be_equal_to(0));
+        expect!(newman_ziff.curve(&[0.2, 0.5, 0.9]).len()).to(be_equal_to(0));
+        expect!(newman_ziff.largest_cluster_fraction(0.5)).to(be_close_to(0.0));
+    }
+}
@@ -0,0 +1,54 @@
+pub use algorithms::percolation::{Percolation, BruteForcePercolation, UnionFindPercolation, HackUnionFindPercolation};
+
+pub use expectest::prelude::{be_none, be_equal_to};
+
+pub fn open_sites(percolation: &mut Percolation, sites: &[(usize, usize)]) {
+    for &(row, col) in sites {
+        percolation.open(row, col);
+    }
+}
+
+describe! percolating_path_tests {
+
+    it "should not have a path when the system does not percolate" {
+        let mut percolation = UnionFindPercolation::new(3);
+        open_sites(&mut percolation, &[(1, 1), (2, 1), (2, 2)]);
+
+        expect!(percolation.percolating_path()).to(be_none());
+    }
+
+    it "should find the shortest path from top to bottom" {
+        let mut percolation = BruteForcePercolation::new(4);
+        open_sites(&mut percolation, &[(1, 1), (2, 1), (3, 1), (3, 2), (3, 3), (2, 3), (1, 3), (4, 3)]);
+
+        expect!(percolation.percolating_path()).to(be_equal_to(Some(vec![(1, 3), (2, 3), (3, 3), (4, 3)])));
+    }
+
+    it "should find a path for every implementation" {
+        let mut hack = HackUnionFindPercolation::new(3);
+        open_sites(&mut hack, &[(1, 2), (2, 2), (2, 3), (3, 3)]);
+
+        expect!(hack.percolating_path()).to(be_equal_to(Some(vec![(1, 2), (2, 2), (2, 3), (3, 3)])));
+    }
+
+    it "should exclude dangling ends from the backbone" {
+        let mut percolation = UnionFindPercolation::new(4);
+        open_sites(&mut percolation, &[(1, 2), (2, 2), (3, 2), (4, 2), (2, 3), (2, 4), (3, 4)]);
+
+        expect!(percolation.backbone()).to(be_equal_to(vec![(1, 2), (2, 2), (3, 2), (4, 2)]));
+    }
+
+    it "should include every branch of a loop in the backbone" {
+        let mut percolation = UnionFindPercolation::new(4);
+        open_sites(&mut percolation, &[(1, 2), (2, 1), (2, 2), (2, 3), (3, 1), (3, 3), (4, 3), (4, 1), (3, 4)]);
+
+        expect!(percolation.backbone()).to(be_equal_to(vec![(1, 2), (2, 1), (2, 2), (2, 3), (3, 1), (3, 3), (4, 1), (4, 3)]));
+    }
+
+    it "should have an empty backbone when the system does not percolate" {
+        let mut percolation = UnionFindPercolation::new(3);
+        open_sites(&mut percolation, &[(1, 1), (2, 1)]);
+
+        expect!(percolation.backbone()).to(be_equal_to(Vec::new()));
+    }
+}
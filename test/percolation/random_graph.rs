@@ -0,0 +1,65 @@
+pub use rand::{SeedableRng, XorShiftRng};
+
+pub use algorithms::percolation::random_graph::{RandomGraph, RandomGraphTrial};
+
+pub use expectest::prelude::{be_true, be_equal_to, be_greater_than, be_less_than, be_close_to};
+
+describe! random_graph_tests {
+
+    before_each {
+        let mut rng = XorShiftRng::from_seed([17, 19, 23, 29]);
+    }
+
+    it "should add edges until the graph is connected" {
+        let trial = RandomGraphTrial::run(100, 0.5, &mut rng);
+        let largest = trial.largest_component_by_edges();
+
+        expect!(largest.len()).to(be_equal_to(trial.connected_at() + 1));
+        expect!(largest[0]).to(be_equal_to(1));
+        expect!(largest[trial.connected_at()]).to(be_equal_to(100));
+    }
+
+    it "should never shrink the largest component" {
+        let trial = RandomGraphTrial::run(100, 0.5, &mut rng);
+        let largest = trial.largest_component_by_edges();
+
+        expect!(largest.windows(2).all(|pair| pair[0] <= pair[1])).to(be_true());
+    }
+
+    it "should find the giant component before the graph is connected" {
+        let trial = RandomGraphTrial::run(100, 0.5, &mut rng);
+
+        expect!(trial.largest_component_by_edges()[trial.giant_component_at()]).to(be_greater_than(49));
+        expect!(trial.largest_component_by_edges()[trial.giant_component_at() - 1]).to(be_less_than(50));
+        expect!(trial.giant_component_at()).to(be_less_than(trial.connected_at()));
+    }
+
+    it "should be connected from the start with a single vertex" {
+        let trial = RandomGraphTrial::run(1, 0.5, &mut rng);
+
+        expect!(trial.connected_at()).to(be_equal_to(0));
+        expect!(trial.giant_component_at()).to(be_equal_to(0));
+    }
+
+    it "should grow half the vertices into a giant component after about n ln 2 edges" {
+        let graph = RandomGraph::run(1000, 20, 0.5, &mut rng);
+
+        expect!(graph.mean_giant_component_at() / 1000.0).to(be_close_to(2f64.ln()).delta(0.05));
+    }
+
+    it "should become connected after about n ln n / 2 edges" {
+        let graph = RandomGraph::run(1000, 20, 0.5, &mut rng);
+        let expected = 1000.0 * 1000f64.ln() / 2.0;
+
+        expect!(graph.mean_connected_at()).to(be_greater_than(0.8 * expected));
+        expect!(graph.mean_connected_at()).to(be_less_than(1.25 * expected));
+    }
+
+    it "should average largest component size over trials" {
+        let graph = RandomGraph::run(50, 5, 0.5, &mut rng);
+        let largest = graph.largest_component_by_edges();
+
+        expect!(largest[0]).to(be_close_to(1.0));
+        expect!(largest[largest.len() - 1]).to(be_close_to(50.0));
+    }
+}
@@ -0,0 +1,67 @@
+pub use algorithms::percolation::{Percolation, Site, UnionFindPercolation};
+pub use algorithms::percolation::render::{to_ascii, to_ansi, write_ppm, write_pgm, to_svg};
+
+pub use expectest::prelude::{be_true, be_equal_to};
+
+describe! render_tests {
+
+    before_each {
+        let mut percolation = UnionFindPercolation::new(3);
+        percolation.open(1, 1);
+        percolation.open(2, 1);
+        percolation.open(3, 3);
+    }
+
+    it "should expose state of every site" {
+        expect!(percolation.site(2, 1)).to(be_equal_to(Site::Full));
+        expect!(percolation.site(3, 3)).to(be_equal_to(Site::Open));
+        expect!(percolation.site(3, 1)).to(be_equal_to(Site::Blocked));
+    }
+
+    it "should render grid as ascii art" {
+        expect!(to_ascii(&percolation)).to(be_equal_to("~##\n~##\n##.\n".to_owned()));
+    }
+
+    it "should render grid with ansi background colors" {
+        let ansi_art = to_ansi(&percolation, &[]);
+        let first_row = "\x1b[48;2;103;198;243m  \x1b[48;2;0;0;0m    \x1b[0m\n";
+
+        expect!(ansi_art.starts_with(first_row)).to(be_true());
+        expect!(ansi_art.lines().count()).to(be_equal_to(3));
+        expect!(ansi_art.ends_with("\x1b[48;2;0;0;0m    \x1b[48;2;255;255;255m  \x1b[0m\n")).to(be_true());
+    }
+
+    it "should render highlighted sites in their own color" {
+        let ansi_art = to_ansi(&percolation, &[(1, 1)]);
+
+        expect!(ansi_art.starts_with("\x1b[48;2;255;196;0m  \x1b[48;2;0;0;0m")).to(be_true());
+    }
+
+    it "should render grid as ppm image" {
+        let mut image = Vec::new();
+
+        write_ppm(&percolation, 2, &mut image).unwrap();
+
+        let header = b"P6\n6 6\n255\n";
+        expect!(image.starts_with(header)).to(be_true());
+        expect!(image.len()).to(be_equal_to(header.len() + 6 * 6 * 3));
+        expect!(image[header.len()..header.len() + 3].to_vec()).to(be_equal_to(vec![103, 198, 243]));
+        expect!(image[header.len() + 6..header.len() + 9].to_vec()).to(be_equal_to(vec![0, 0, 0]));
+    }
+
+    it "should render grid as pgm image" {
+        let mut image = Vec::new();
+
+        write_pgm(&percolation, 1, &mut image).unwrap();
+
+        let header = b"P5\n3 3\n255\n";
+        expect!(image[header.len()..].to_vec()).to(be_equal_to(vec![128, 0, 0, 128, 0, 0, 0, 0, 255]));
+    }
+
+    it "should render grid as svg" {
+        let svg = to_svg(&percolation, 10);
+
+        expect!(svg.matches("<rect").count()).to(be_equal_to(9));
+        expect!(svg.contains("<rect x=\"20\" y=\"20\" width=\"10\" height=\"10\" fill=\"#ffffff\"/>")).to(be_true());
+    }
+}
@@ -0,0 +1,102 @@
+pub use std::env;
+pub use std::fs::File;
+pub use std::io::{Cursor, ErrorKind, Write};
+
+pub use algorithms::percolation::{Percolation, PercolationError, BruteForcePercolation, UnionFindPercolation, HackUnionFindPercolation};
+pub use algorithms::percolation::replay::{Step, replay, replay_file, read_sites};
+
+pub use expectest::prelude::{be_true, be_err, be_none, be_equal_to};
+
+describe! replay_tests {
+
+    it "should read side size and sites" {
+        let input = Cursor::new("3\n1 1\n 2 3 \n\n3 2\n");
+
+        expect!(read_sites(input).unwrap()).to(be_equal_to((3, vec![(1, 1), (2, 3), (3, 2)])));
+    }
+
+    it "should reject a site without column" {
+        expect!(read_sites(Cursor::new("3\n1 1\n2\n"))).to(be_err());
+    }
+
+    it "should reject trailing data after a site" {
+        expect!(read_sites(Cursor::new("3\n1 1 2\n"))).to(be_err());
+    }
+
+    it "should reject input without side size" {
+        expect!(read_sites(Cursor::new(""))).to(be_err());
+    }
+
+    it "should report the step at which the system percolates" {
+        let mut percolation = UnionFindPercolation::new(3);
+
+        let replay = replay(&mut percolation, vec![(1, 1), (3, 3), (2, 1), (2, 1), (3, 1), (1, 2)], false).unwrap();
+
+        expect!(replay.percolated_at()).to(be_equal_to(Some(4)));
+        expect!(replay.open_sites()).to(be_equal_to(Some(4)));
+        expect!(replay.steps()).to(be_equal_to(6));
+        expect!(replay.trace()).to(be_none());
+    }
+
+    it "should not report percolation when the system does not percolate" {
+        let mut percolation = BruteForcePercolation::new(3);
+
+        let replay = replay(&mut percolation, vec![(1, 1), (2, 1)], false).unwrap();
+
+        expect!(replay.percolated_at()).to(be_none());
+        expect!(replay.open_sites()).to(be_none());
+    }
+
+    it "should count sites that were open before the replay" {
+        let mut percolation = UnionFindPercolation::new(3);
+        percolation.open(1, 1);
+        percolation.open(2, 3);
+
+        let replay = replay(&mut percolation, vec![(2, 1), (3, 1)], true).unwrap();
+
+        expect!(replay.open_sites()).to(be_equal_to(Some(4)));
+        expect!(replay.trace().unwrap()[0].open_sites).to(be_equal_to(3));
+    }
+
+    it "should report site out of the grid" {
+        let mut percolation = UnionFindPercolation::new(3);
+
+        expect!(replay(&mut percolation, vec![(1, 1), (4, 1)], false).err())
+            .to(be_equal_to(Some(PercolationError::OutOfBounds { row: 4, col: 1, side_size: 3 })));
+    }
+
+    it "should report invalid file as invalid data" {
+        let path = env::temp_dir().join("replay_tests_invalid_sites");
+        for content in ["0\n", "2\n1 1\n3 1\n"].iter() {
+            File::create(&path).unwrap().write_all(content.as_bytes()).unwrap();
+
+            let error = replay_file(&path, UnionFindPercolation::try_new, false).err().unwrap();
+
+            expect!(error.kind()).to(be_equal_to(ErrorKind::InvalidData));
+        }
+    }
+
+    it "should trace every step" {
+        let mut percolation = HackUnionFindPercolation::new(2);
+
+        let replay = replay(&mut percolation, vec![(1, 2), (1, 2), (2, 2)], true).unwrap();
+
+        expect!(replay.trace().unwrap().to_vec()).to(be_equal_to(vec![
+            Step { row: 1, col: 2, open_sites: 1, percolates: false },
+            Step { row: 1, col: 2, open_sites: 1, percolates: false },
+            Step { row: 2, col: 2, open_sites: 2, percolates: true }
+        ]));
+    }
+
+    it "should agree between implementations on bench files" {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/benches/percolation_100x100");
+
+        let (_, brute_force) = replay_file(path, BruteForcePercolation::try_new, false).unwrap();
+        let (_, union_find) = replay_file(path, UnionFindPercolation::try_new, false).unwrap();
+        let (_, hack) = replay_file(path, HackUnionFindPercolation::try_new, false).unwrap();
+
+        expect!(brute_force.percolated_at().is_some()).to(be_true());
+        expect!(union_find.clone()).to(be_equal_to(brute_force.clone()));
+        expect!(hack).to(be_equal_to(brute_force));
+    }
+}
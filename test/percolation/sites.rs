@@ -0,0 +1,66 @@
+pub use algorithms::percolation::Site;
+pub use algorithms::percolation::sites::SiteStates;
+
+pub use expectest::prelude::{be_true, be_false, be_equal_to};
+
+describe! site_states_tests {
+
+    before_each {
+        let mut states = SiteStates::new(100);
+    }
+
+    it "should block every site initially" {
+        expect!(states.get(0)).to(be_equal_to(Site::Blocked));
+        expect!(states.get(99)).to(be_equal_to(Site::Blocked));
+        expect!(states.count_open()).to(be_equal_to(0));
+    }
+
+    it "should block a full site again" {
+        states.make_full(40);
+        states.make_blocked(40);
+
+        expect!(states.get(40)).to(be_equal_to(Site::Blocked));
+        expect!(states.count_full()).to(be_equal_to(0));
+    }
+
+    it "should open a site without touching its neighbors" {
+        states.make_open(32);
+
+        expect!(states.get(32)).to(be_equal_to(Site::Open));
+        expect!(states.is_open(31)).to(be_false());
+        expect!(states.is_open(33)).to(be_false());
+    }
+
+    it "should make a site full and open" {
+        states.make_full(63);
+
+        expect!(states.is_open(63)).to(be_true());
+        expect!(states.is_full(63)).to(be_true());
+        expect!(states.get(63)).to(be_equal_to(Site::Full));
+    }
+
+    it "should count open and full sites across words" {
+        states.make_open(0);
+        states.make_full(31);
+        states.make_full(32);
+        states.make_open(99);
+
+        expect!(states.count_open()).to(be_equal_to(4));
+        expect!(states.count_full()).to(be_equal_to(2));
+    }
+
+    it "should scan ranges for full sites" {
+        states.make_full(40);
+        states.make_open(70);
+
+        expect!(states.any_full_in(41, 100)).to(be_false());
+        expect!(states.any_full_in(30, 41)).to(be_true());
+        expect!(states.count_open_in(40, 71)).to(be_equal_to(2));
+        expect!(states.count_open_in(41, 70)).to(be_equal_to(0));
+    }
+
+    it "should keep number of sites" {
+        expect!(states.len()).to(be_equal_to(100));
+        expect!(SiteStates::new(0).is_empty()).to(be_true());
+    }
+}
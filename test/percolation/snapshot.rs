@@ -0,0 +1,225 @@
+pub use std::io::ErrorKind;
+
+pub use rand::{SeedableRng, XorShiftRng};
+
+pub use algorithms::percolation::{Percolation, BruteForcePercolation, UnionFindPercolation, HackUnionFindPercolation};
+pub use algorithms::percolation::stats::random_order;
+
+pub use expectest::prelude::{be_true, be_false, be_err, be_equal_to};
+
+pub const SIDE_SIZE: usize = 13;
+
+pub fn random_sites(seed: u32) -> Vec<(usize, usize)> {
+    let mut rng = XorShiftRng::from_seed([seed, 3, 5, 7]);
+    random_order(SIDE_SIZE * SIDE_SIZE, &mut rng).into_iter()
+        .map(|index| (index / SIDE_SIZE + 1, index % SIDE_SIZE + 1))
+        .collect()
+}
+
+pub fn header(kind: u8, side_size: u64) -> Vec<u8> {
+    let mut snapshot = b"PERC\x02\x00".to_vec();
+    snapshot.push(kind);
+    snapshot.extend((0..8).map(|i| (side_size >> (8 * i)) as u8));
+    snapshot
+}
+
+pub fn expect_same(first: &Percolation, second: &Percolation) {
+    expect!(second.side_size()).to(be_equal_to(first.side_size()));
+    expect!(second.percolates()).to(be_equal_to(first.percolates()));
+    for row in 1..SIDE_SIZE + 1 {
+        for col in 1..SIDE_SIZE + 1 {
+            expect!(second.site(row, col)).to(be_equal_to(first.site(row, col)));
+        }
+    }
+}
+
+describe! snapshot_tests {
+
+    describe! restoring {
+
+        before_each {
+            let sites = random_sites(11);
+            let (before, after) = sites.split_at(90);
+        }
+
+        it "should restore brute force percolation" {
+            let mut original = BruteForcePercolation::new(SIDE_SIZE);
+            for &(row, col) in before {
+                original.open(row, col);
+            }
+            let mut snapshot = Vec::new();
+            original.save(&mut snapshot).unwrap();
+            let mut restored = BruteForcePercolation::load(&mut snapshot.as_slice()).unwrap();
+
+            expect_same(&original, &restored);
+            for &(row, col) in after {
+                original.open(row, col);
+                restored.open(row, col);
+            }
+            expect_same(&original, &restored);
+        }
+
+        it "should restore union find percolation" {
+            let mut original = UnionFindPercolation::new(SIDE_SIZE);
+            for &(row, col) in before {
+                original.open(row, col);
+            }
+            let mut snapshot = Vec::new();
+            original.save(&mut snapshot).unwrap();
+            let mut restored = UnionFindPercolation::load(&mut snapshot.as_slice()).unwrap();
+
+            expect_same(&original, &restored);
+            for &(row, col) in after {
+                original.open(row, col);
+                restored.open(row, col);
+                expect!(restored.percolates()).to(be_equal_to(original.percolates()));
+            }
+            expect_same(&original, &restored);
+        }
+
+        it "should restore hack union find percolation" {
+            let mut original = HackUnionFindPercolation::new(SIDE_SIZE);
+            for &(row, col) in before {
+                original.open(row, col);
+            }
+            let mut snapshot = Vec::new();
+            original.save(&mut snapshot).unwrap();
+            let mut restored = HackUnionFindPercolation::load(&mut snapshot.as_slice()).unwrap();
+
+            expect_same(&original, &restored);
+            for &(row, col) in after {
+                original.open(row, col);
+                restored.open(row, col);
+                expect!(restored.percolates()).to(be_equal_to(original.percolates()));
+            }
+            expect_same(&original, &restored);
+        }
+
+        it "should produce identical snapshots for identical states" {
+            let mut first = UnionFindPercolation::new(SIDE_SIZE);
+            for &(row, col) in before.iter().chain(after.iter().take(20)) {
+                first.open(row, col);
+            }
+            let mut snapshot = Vec::new();
+            first.save(&mut snapshot).unwrap();
+            let mut resaved = Vec::new();
+            UnionFindPercolation::load(&mut snapshot.as_slice()).unwrap().save(&mut resaved).unwrap();
+
+            expect!(resaved).to(be_equal_to(snapshot));
+        }
+    }
+
+    it "should derive percolation from full sites on the bottom row" {
+        let mut percolation = HackUnionFindPercolation::new(3);
+        percolation.open(1, 2);
+        percolation.open(2, 2);
+        let mut snapshot = Vec::new();
+        percolation.save(&mut snapshot).unwrap();
+
+        expect!(HackUnionFindPercolation::load(&mut snapshot.as_slice()).unwrap().percolates()).to(be_false());
+
+        percolation.open(3, 2);
+        let mut snapshot = Vec::new();
+        percolation.save(&mut snapshot).unwrap();
+
+        expect!(HackUnionFindPercolation::load(&mut snapshot.as_slice()).unwrap().percolates()).to(be_true());
+    }
+
+    it "should start with a version header" {
+        let mut snapshot = Vec::new();
+        BruteForcePercolation::new(2).save(&mut snapshot).unwrap();
+
+        expect!(&snapshot[0..7]).to(be_equal_to(&b"PERC\x02\x00\x01"[..]));
+    }
+
+    it "should reject snapshot of another implementation" {
+        let mut snapshot = Vec::new();
+        BruteForcePercolation::new(SIDE_SIZE).save(&mut snapshot).unwrap();
+
+        expect!(UnionFindPercolation::load(&mut snapshot.as_slice()).map(|_| ())).to(be_err());
+    }
+
+    it "should reject unknown version" {
+        let mut snapshot = Vec::new();
+        UnionFindPercolation::new(SIDE_SIZE).save(&mut snapshot).unwrap();
+        snapshot[4] = 1;
+
+        let error = UnionFindPercolation::load(&mut snapshot.as_slice()).map(|_| ()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::InvalidData));
+    }
+
+    it "should reject data that is not a snapshot" {
+        expect!(BruteForcePercolation::load(&mut &b"P1\n2 2\n0 1\n1 0\n"[..]).map(|_| ())).to(be_err());
+    }
+
+    it "should reject truncated snapshot" {
+        let mut snapshot = Vec::new();
+        HackUnionFindPercolation::new(SIDE_SIZE).save(&mut snapshot).unwrap();
+        let length = snapshot.len();
+
+        let error = HackUnionFindPercolation::load(&mut &snapshot[..length - 1]).map(|_| ()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::UnexpectedEof));
+    }
+
+    it "should reject side size whose grid overflows" {
+        let error = BruteForcePercolation::load(&mut header(1, 1 << 33).as_slice()).map(|_| ()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::InvalidData));
+
+        let error = HackUnionFindPercolation::load(&mut header(3, ::std::u64::MAX).as_slice()).map(|_| ()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::InvalidData));
+    }
+
+    it "should not allocate more than the snapshot holds" {
+        let mut snapshot = header(1, 1 << 31);
+        snapshot.extend((0..8).map(|i| ((1u64 << 62) >> (8 * i)) as u8));
+
+        let error = BruteForcePercolation::load(&mut snapshot.as_slice()).map(|_| ()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::UnexpectedEof));
+    }
+
+    it "should reject full site that is not open" {
+        let mut snapshot = Vec::new();
+        BruteForcePercolation::new(2).save(&mut snapshot).unwrap();
+        snapshot[23] = 0b10;
+
+        let error = BruteForcePercolation::load(&mut snapshot.as_slice()).map(|_| ()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::InvalidData));
+    }
+
+    it "should reject open site outside of the hack grid" {
+        let mut snapshot = Vec::new();
+        HackUnionFindPercolation::new(3).save(&mut snapshot).unwrap();
+        snapshot[23] = 0b01 << 6;
+
+        let error = HackUnionFindPercolation::load(&mut snapshot.as_slice()).map(|_| ()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::InvalidData));
+    }
+
+    it "should reject cyclic union find" {
+        let mut snapshot = Vec::new();
+        UnionFindPercolation::new(2).save(&mut snapshot).unwrap();
+        let elements = snapshot.len() - 5 * 9;
+        snapshot[elements] = 1;
+        snapshot[elements + 9] = 0;
+
+        let error = UnionFindPercolation::load(&mut snapshot.as_slice()).map(|_| ()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::InvalidData));
+    }
+
+    it "should reject union find parent beyond 32 bits" {
+        let mut snapshot = Vec::new();
+        UnionFindPercolation::new(2).save(&mut snapshot).unwrap();
+        let elements = snapshot.len() - 5 * 9;
+        snapshot[elements + 4] = 1;
+
+        let error = UnionFindPercolation::load(&mut snapshot.as_slice()).map(|_| ()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::InvalidData));
+    }
+
+    it "should refuse to save percolation with other boundaries" {
+        let percolation = UnionFindPercolation::with_boundaries(3, &[(1, 1)], &[(3, 3)]);
+
+        let error = percolation.save(&mut Vec::new()).unwrap_err();
+        expect!(error.kind()).to(be_equal_to(ErrorKind::InvalidInput));
+    }
+}
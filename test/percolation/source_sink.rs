@@ -0,0 +1,108 @@
+pub use algorithms::percolation::{Percolation, PercolationError, UnionFindPercolation};
+pub use algorithms::percolation::source_sink::{top_row, bottom_row, left_column, right_column,
+    boundary, center};
+
+pub use expectest::prelude::{be_true, be_false, be_none, be_equal_to};
+
+describe! source_sink_percolation_tests {
+
+    describe! left_to_right {
+
+        before_each {
+            const SIDE_SIZE: usize = 4;
+            let mut percolation =
+                UnionFindPercolation::with_boundaries(SIDE_SIZE, &left_column(SIDE_SIZE), &right_column(SIDE_SIZE));
+        }
+
+        it "should be full when opened site is a source" {
+            percolation.open(3, 1);
+
+            expect!(percolation.is_full(3, 1)).to(be_true());
+        }
+
+        it "should not be full on the top row unless connected to a source" {
+            percolation.open(1, 2);
+
+            expect!(percolation.is_full(1, 2)).to(be_false());
+        }
+
+        it "should percolate when a row of open sites crosses the grid" {
+            for col in 1..SIDE_SIZE + 1 {
+                percolation.open(2, col);
+            }
+
+            expect!(percolation.percolates()).to(be_true());
+            expect!(percolation.percolating_path())
+                .to(be_equal_to(Some(vec![(2, 1), (2, 2), (2, 3), (2, 4)])));
+        }
+
+        it "should not percolate when a column of open sites crosses the grid" {
+            for row in 1..SIDE_SIZE + 1 {
+                percolation.open(row, 2);
+            }
+
+            expect!(percolation.percolates()).to(be_false());
+            expect!(percolation.percolating_path()).to(be_none());
+        }
+
+        it "should not contain backwash" {
+            for col in 1..SIDE_SIZE + 1 {
+                percolation.open(2, col);
+            }
+            percolation.open(4, 4);
+
+            expect!(percolation.is_full(4, 4)).to(be_false());
+        }
+    }
+
+    describe! center_to_boundary {
+
+        before_each {
+            const SIDE_SIZE: usize = 5;
+            let mut percolation =
+                UnionFindPercolation::with_boundaries(SIDE_SIZE, &center(SIDE_SIZE), &boundary(SIDE_SIZE));
+        }
+
+        it "should use a single site center for odd side size" {
+            expect!(center(SIDE_SIZE)).to(be_equal_to(vec![(3, 3)]));
+            expect!(center(4)).to(be_equal_to(vec![(2, 2), (2, 3), (3, 2), (3, 3)]));
+        }
+
+        it "should percolate when center connects to the boundary" {
+            percolation.open(3, 3);
+            percolation.open(3, 4);
+
+            expect!(percolation.percolates()).to(be_false());
+
+            percolation.open(3, 5);
+
+            expect!(percolation.percolates()).to(be_true());
+        }
+
+        it "should percolate when the boundary is opened before the center" {
+            percolation.open(1, 3);
+            percolation.open(2, 3);
+            percolation.open(3, 3);
+
+            expect!(percolation.percolates()).to(be_true());
+            expect!(percolation.backbone()).to(be_equal_to(vec![(1, 3), (2, 3), (3, 3)]));
+        }
+    }
+
+    it "should behave like top to bottom percolation with top and bottom rows" {
+        let mut percolation = UnionFindPercolation::with_boundaries(3, &top_row(3), &bottom_row(3));
+        let mut union_find = UnionFindPercolation::new(3);
+        for &(row, col) in [(2, 2), (3, 2), (1, 1), (2, 1)].iter() {
+            percolation.open(row, col);
+            union_find.open(row, col);
+        }
+
+        expect!(percolation.percolates()).to(be_equal_to(union_find.percolates()));
+        expect!(percolation.is_full(3, 2)).to(be_equal_to(union_find.is_full(3, 2)));
+    }
+
+    it "should reject sources out of bounds" {
+        expect!(UnionFindPercolation::try_with_boundaries(3, &[(4, 1)], &[(1, 1)]).err())
+            .to(be_equal_to(Some(PercolationError::OutOfBounds { row: 4, col: 1, side_size: 3 })));
+    }
+}
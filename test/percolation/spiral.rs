@@ -0,0 +1,66 @@
+pub use algorithms::percolation::{Percolation, BruteForcePercolation, UnionFindPercolation, HackUnionFindPercolation};
+
+pub use expectest::prelude::{be_true, be_greater_than};
+
+const SIDE_SIZE: usize = 500;
+
+// Walks a square spiral below the top row, keeping one blocked site between its rings,
+// so every site of the spiral is a single chain hanging from (1, 1).
+pub fn spiral(side_size: usize) -> Vec<(usize, usize)> {
+    let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+    let mut visited = vec![vec![false; side_size + 2]; side_size + 2];
+    let mut path = vec![(2, 1)];
+    visited[2][1] = true;
+    let mut direction = 0;
+    let mut turns = 0;
+    while turns < 2 {
+        let (row, col) = path[path.len() - 1];
+        let (dr, dc) = directions[direction];
+        let next = ((row as isize + dr) as usize, (col as isize + dc) as usize);
+        let inside = next.0 >= 2 && next.0 <= side_size && next.1 >= 1 && next.1 <= side_size;
+        let free = inside && !visited[next.0][next.1]
+            && directions.iter()
+                .map(|&(r, c)| ((next.0 as isize + r) as usize, (next.1 as isize + c) as usize))
+                .all(|neighbor| neighbor == (row, col) || !visited[neighbor.0][neighbor.1]);
+        if free {
+            visited[next.0][next.1] = true;
+            path.push(next);
+            turns = 0;
+        }
+        else {
+            direction = (direction + 1) % 4;
+            turns += 1;
+        }
+    }
+    path
+}
+
+pub fn fill_spiral(percolation: &mut Percolation) {
+    let path = spiral(SIDE_SIZE);
+    for &(row, col) in path.iter() {
+        percolation.open(row, col);
+    }
+    percolation.open(1, 1);
+
+    let (row, col) = path[path.len() - 1];
+    expect!(percolation.is_full(row, col)).to(be_true());
+}
+
+describe! spiral_fill_tests {
+
+    it "should wind a long spiral" {
+        expect!(spiral(SIDE_SIZE).len()).to(be_greater_than(SIDE_SIZE * SIDE_SIZE / 3));
+    }
+
+    it "should fill a spiral without overflowing the stack in brute force percolation" {
+        fill_spiral(&mut BruteForcePercolation::new(SIDE_SIZE));
+    }
+
+    it "should fill a spiral without overflowing the stack in union find percolation" {
+        fill_spiral(&mut UnionFindPercolation::new(SIDE_SIZE));
+    }
+
+    it "should fill a spiral without overflowing the stack in hack union find percolation" {
+        fill_spiral(&mut HackUnionFindPercolation::new(SIDE_SIZE));
+    }
+}
@@ -0,0 +1,56 @@
+pub use algorithms::percolation::{UnionFindPercolation, HackUnionFindPercolation};
+pub use algorithms::percolation::stats::PercolationStats;
+
+pub use expectest::prelude::{be_true, be_equal_to, be_close_to};
+
+describe! percolation_stats_tests {
+
+    it "should compute mean and standard deviation of thresholds" {
+        let stats = PercolationStats::new(vec![0.5, 0.6, 0.7]);
+
+        expect!(stats.mean()).to(be_close_to(0.6));
+        expect!(stats.stddev()).to(be_close_to(0.1));
+    }
+
+    it "should compute 95% confidence interval" {
+        let stats = PercolationStats::new(vec![0.5, 0.6, 0.7]);
+
+        expect!(stats.confidence_lo()).to(be_close_to(0.6 - 1.96 * 0.1 / 3f64.sqrt()));
+        expect!(stats.confidence_hi()).to(be_close_to(0.6 + 1.96 * 0.1 / 3f64.sqrt()));
+    }
+
+    it "should produce identical thresholds whatever the thread count" {
+        let single = PercolationStats::parallel(20, 12, 1, 42, UnionFindPercolation::new);
+        let triple = PercolationStats::parallel(20, 12, 3, 42, UnionFindPercolation::new);
+        let many = PercolationStats::parallel(20, 12, 16, 42, UnionFindPercolation::new);
+
+        expect!(triple.thresholds()).to(be_equal_to(single.thresholds()));
+        expect!(many.thresholds()).to(be_equal_to(single.thresholds()));
+    }
+
+    it "should produce identical thresholds for every implementation" {
+        let union_find = PercolationStats::parallel(20, 8, 2, 7, UnionFindPercolation::new);
+        let hack = PercolationStats::parallel(20, 8, 4, 7, HackUnionFindPercolation::new);
+
+        expect!(hack.thresholds()).to(be_equal_to(union_find.thresholds()));
+    }
+
+    it "should produce different thresholds for different seeds" {
+        let first = PercolationStats::parallel(20, 8, 2, 1, UnionFindPercolation::new);
+        let second = PercolationStats::parallel(20, 8, 2, 2, UnionFindPercolation::new);
+
+        expect!(first.thresholds() != second.thresholds()).to(be_true());
+    }
+
+    it "should run any trials on several threads in trial order" {
+        let stats = PercolationStats::parallel_trials(10, 3, |trial| trial as f64);
+
+        expect!(stats.thresholds().to_vec()).to(be_equal_to((0..10).map(|trial| trial as f64).collect::<Vec<_>>()));
+    }
+
+    it "should estimate site threshold near 0.5927" {
+        let stats = PercolationStats::parallel(50, 40, 4, 2016, UnionFindPercolation::new);
+
+        expect!(stats.mean()).to(be_close_to(0.5927).delta(0.03));
+    }
+}
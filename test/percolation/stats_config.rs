@@ -0,0 +1,64 @@
+pub use algorithms::percolation::factory::Implementation;
+pub use algorithms::percolation::stats_config::{StatsConfig, Lattice, Boundary};
+
+pub use expectest::prelude::{be_true, be_false, be_ok, be_err, be_equal_to};
+
+pub fn config(lattice: Lattice, boundary: Boundary) -> StatsConfig {
+    StatsConfig {
+        size: 10,
+        trials: 4,
+        threads: 2,
+        implementation: Implementation::UnionFind,
+        seed: 7,
+        lattice: lattice,
+        boundary: boundary
+    }
+}
+
+describe! stats_config_tests {
+
+    it "should accept every lattice with top-bottom boundary" {
+        expect!(config(Lattice::Site, Boundary::TopBottom).check()).to(be_ok());
+        expect!(config(Lattice::Bond, Boundary::TopBottom).check()).to(be_ok());
+        expect!(config(Lattice::Cubic, Boundary::TopBottom).check()).to(be_ok());
+        expect!(config(Lattice::Directed, Boundary::TopBottom).check()).to(be_ok());
+    }
+
+    it "should use the implementation only for the site lattice with top-bottom boundary" {
+        expect!(config(Lattice::Site, Boundary::TopBottom).uses_implementation()).to(be_true());
+        expect!(config(Lattice::Site, Boundary::LeftRight).uses_implementation()).to(be_false());
+        expect!(config(Lattice::Cubic, Boundary::TopBottom).uses_implementation()).to(be_false());
+    }
+
+    it "should reject bond lattice without bonds" {
+        let mut bond = config(Lattice::Bond, Boundary::TopBottom);
+        bond.size = 1;
+
+        expect!(bond.check()).to(be_err());
+    }
+
+    it "should reject boundaries outside the site lattice" {
+        expect!(config(Lattice::Directed, Boundary::BoundaryCenter).check()).to(be_err());
+    }
+
+    it "should reject zero sizes, trials and threads" {
+        let mut empty = config(Lattice::Site, Boundary::TopBottom);
+        empty.size = 0;
+        let mut no_trials = config(Lattice::Site, Boundary::TopBottom);
+        no_trials.trials = 0;
+        let mut no_threads = config(Lattice::Site, Boundary::TopBottom);
+        no_threads.threads = 0;
+
+        expect!(empty.check()).to(be_err());
+        expect!(no_trials.check()).to(be_err());
+        expect!(no_threads.check()).to(be_err());
+    }
+
+    it "should repeat the same thresholds for the same seed" {
+        let first = config(Lattice::Site, Boundary::LeftRight).run();
+        let second = config(Lattice::Site, Boundary::LeftRight).run();
+
+        expect!(first.thresholds().len()).to(be_equal_to(4));
+        expect!(first.thresholds().to_vec()).to(be_equal_to(second.thresholds().to_vec()));
+    }
+}
@@ -0,0 +1,138 @@
+pub use rand::{Rng, SeedableRng, XorShiftRng};
+
+pub use algorithms::percolation::{Percolation, UnionFindPercolation};
+pub use algorithms::percolation::undo::UndoablePercolation;
+
+pub use expectest::prelude::{be_true, be_false, be_some, be_none, be_equal_to};
+
+describe! undoable_percolation_tests {
+
+    before_each {
+        const SIDE_SIZE: usize = 4;
+        let mut percolation = UndoablePercolation::new(SIDE_SIZE);
+    }
+
+    it "should have nothing to undo or redo initially" {
+        expect!(percolation.undo()).to(be_none());
+        expect!(percolation.redo()).to(be_none());
+    }
+
+    it "should close undone site" {
+        percolation.open(2, 3);
+
+        expect!(percolation.undo()).to(be_equal_to(Some((2, 3))));
+        expect!(percolation.is_open(2, 3)).to(be_false());
+        expect!(percolation.number_of_open_sites()).to(be_equal_to(0));
+    }
+
+    it "should drain sites filled through undone site" {
+        percolation.open(2, 1);
+        percolation.open(1, 1);
+
+        expect!(percolation.is_full(2, 1)).to(be_true());
+
+        percolation.undo();
+
+        expect!(percolation.is_full(2, 1)).to(be_false());
+        expect!(percolation.is_open(2, 1)).to(be_true());
+    }
+
+    it "should restore percolation status" {
+        for row in 1..SIDE_SIZE + 1 {
+            percolation.open(row, 2);
+        }
+
+        expect!(percolation.percolates()).to(be_true());
+
+        percolation.undo();
+
+        expect!(percolation.percolates()).to(be_false());
+
+        percolation.redo();
+
+        expect!(percolation.percolates()).to(be_true());
+    }
+
+    it "should not fill bottom sites through the percolating cluster" {
+        for row in 1..SIDE_SIZE + 1 {
+            percolation.open(row, 1);
+        }
+        percolation.open(SIDE_SIZE, 3);
+
+        expect!(percolation.is_full(SIDE_SIZE, 3)).to(be_false());
+    }
+
+    it "should redo in reverse order of undo" {
+        percolation.open(1, 1);
+        percolation.open(3, 3);
+        percolation.undo();
+        percolation.undo();
+
+        expect!(percolation.redo()).to(be_equal_to(Some((1, 1))));
+        expect!(percolation.redo()).to(be_equal_to(Some((3, 3))));
+        expect!(percolation.redo()).to(be_none());
+    }
+
+    it "should forget redo history on a new opening" {
+        percolation.open(1, 1);
+        percolation.undo();
+        percolation.open(2, 2);
+
+        expect!(percolation.can_redo()).to(be_false());
+        expect!(percolation.undo()).to(be_some());
+        expect!(percolation.can_undo()).to(be_false());
+    }
+
+    it "should not record opening of an open site" {
+        percolation.open(1, 1);
+        percolation.open(1, 1);
+        percolation.undo();
+
+        expect!(percolation.can_undo()).to(be_false());
+    }
+
+    it "should match a percolation rebuilt from the remaining openings" {
+        const RANDOM_SIDE_SIZE: usize = 10;
+        let mut rng = XorShiftRng::from_seed([7, 77, 777, 7777]);
+        let mut percolation = UndoablePercolation::new(RANDOM_SIDE_SIZE);
+        let mut opened = Vec::new();
+        let mut undone = Vec::new();
+        for _ in 0..400 {
+            let action = rng.gen_range(0, 10);
+            if action < 6 {
+                let site = (rng.gen_range(1, RANDOM_SIDE_SIZE + 1), rng.gen_range(1, RANDOM_SIDE_SIZE + 1));
+                if !percolation.is_open(site.0, site.1) {
+                    opened.push(site);
+                    undone.clear();
+                }
+                percolation.open(site.0, site.1);
+            }
+            else if action < 9 {
+                let site = percolation.undo();
+                expect!(site).to(be_equal_to(opened.pop()));
+                if let Some(site) = site {
+                    undone.push(site);
+                }
+            }
+            else {
+                let site = percolation.redo();
+                expect!(site).to(be_equal_to(undone.pop()));
+                if let Some(site) = site {
+                    opened.push(site);
+                }
+            }
+
+            let mut rebuilt = UnionFindPercolation::new(RANDOM_SIDE_SIZE);
+            for &(row, col) in opened.iter() {
+                rebuilt.open(row, col);
+            }
+            expect!(percolation.percolates()).to(be_equal_to(rebuilt.percolates()));
+            expect!(percolation.number_of_open_sites()).to(be_equal_to(opened.len()));
+            for row in 1..RANDOM_SIDE_SIZE + 1 {
+                for col in 1..RANDOM_SIDE_SIZE + 1 {
+                    expect!(percolation.site(row, col)).to(be_equal_to(rebuilt.site(row, col)));
+                }
+            }
+        }
+    }
+}
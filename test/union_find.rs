@@ -1,6 +1,7 @@
-pub use algorithms::union_find::{UnionFind, QuickFind, QuickUnion, WeightedQuickUnion, PathCompressionWeightedQuickUnion};
+pub use algorithms::union_find::{UnionFind, QuickFind, QuickUnion, WeightedQuickUnion, PathCompressionWeightedQuickUnion,
+    CompactRankedQuickUnion, pairs, read_pairs, connect_pairs};
 
-pub use expectest::prelude::{be_true, be_false, be_equal_to};
+pub use expectest::prelude::{be_true, be_false, be_err, be_none, be_equal_to};
 
 describe! union_find_tests {
 
@@ -156,6 +157,44 @@ describe! union_find_tests {
 
             expect!(weighted_quick_union.find(6)).to(be_equal_to(2));
         }
+
+        it "should count points in a component" {
+            weighted_quick_union.union(1, 2);
+            weighted_quick_union.union(3, 2);
+
+            expect!(weighted_quick_union.size(1)).to(be_equal_to(3));
+            expect!(weighted_quick_union.size(4)).to(be_equal_to(1));
+        }
+
+        it "should not grow a component when uniting connected points" {
+            weighted_quick_union.union(1, 2);
+            weighted_quick_union.union(2, 3);
+            weighted_quick_union.union(1, 3);
+
+            expect!(weighted_quick_union.size(3)).to(be_equal_to(3));
+        }
+
+        it "should report roots merged by a link" {
+            weighted_quick_union.union(1, 2);
+
+            expect!(weighted_quick_union.link(3, 1)).to(be_equal_to(Some((3, 2))));
+            expect!(weighted_quick_union.link(3, 2)).to(be_none());
+        }
+
+        it "should split components again when links are cut in reverse order" {
+            let first = weighted_quick_union.link(1, 2).unwrap();
+            let second = weighted_quick_union.link(3, 1).unwrap();
+
+            weighted_quick_union.unlink(second.0, second.1);
+
+            expect!(weighted_quick_union.connected(1, 3)).to(be_false());
+            expect!(weighted_quick_union.size(1)).to(be_equal_to(2));
+
+            weighted_quick_union.unlink(first.0, first.1);
+
+            expect!(weighted_quick_union.connected(1, 2)).to(be_false());
+            expect!(weighted_quick_union.size(2)).to(be_equal_to(1));
+        }
     }
 
     describe! path_compression_weighted_quick_union_tests {
@@ -204,5 +243,107 @@ describe! union_find_tests {
 
             expect!(path_compression_weighted_quick_union.find(6)).to(be_equal_to(2));
         }
+
+        it "should count points in a component" {
+            path_compression_weighted_quick_union.union(1, 2);
+            path_compression_weighted_quick_union.union(3, 2);
+
+            expect!(path_compression_weighted_quick_union.size(1)).to(be_equal_to(3));
+            expect!(path_compression_weighted_quick_union.size(4)).to(be_equal_to(1));
+        }
+
+        it "should not grow a component when uniting connected points" {
+            path_compression_weighted_quick_union.union(1, 2);
+            path_compression_weighted_quick_union.union(2, 3);
+            path_compression_weighted_quick_union.union(1, 3);
+
+            expect!(path_compression_weighted_quick_union.size(3)).to(be_equal_to(3));
+        }
+    }
+
+    describe! compact_ranked_quick_union_tests {
+
+        before_each {
+            let mut compact_ranked_quick_union = CompactRankedQuickUnion::new(10);
+        }
+
+        it "should root be point itself" {
+            expect!(compact_ranked_quick_union.find(1)).to(be_equal_to(1));
+            expect!(compact_ranked_quick_union.find(2)).to(be_equal_to(2));
+        }
+
+        it "should be connected having same united point" {
+            compact_ranked_quick_union.union(1, 2);
+            compact_ranked_quick_union.union(2, 3);
+
+            expect!(compact_ranked_quick_union.connected(1, 3)).to(be_true());
+            expect!(compact_ranked_quick_union.connected(1, 4)).to(be_false());
+        }
+
+        it "should root be a point of the higher tree" {
+            compact_ranked_quick_union.union(1, 2);
+            compact_ranked_quick_union.union(3, 2);
+
+            expect!(compact_ranked_quick_union.find(3)).to(be_equal_to(2));
+        }
+
+        it "should restore from parts" {
+            compact_ranked_quick_union.union(1, 2);
+            compact_ranked_quick_union.union(3, 2);
+            let parents = compact_ranked_quick_union.parents().to_vec();
+            let ranks = compact_ranked_quick_union.ranks().to_vec();
+
+            expect!(CompactRankedQuickUnion::from_parts(parents, ranks).map(|mut union_find| union_find.connected(1, 3)))
+                .to(be_equal_to(Some(true)));
+        }
+
+        it "should not restore cycles" {
+            expect!(CompactRankedQuickUnion::from_parts(vec![1, 0], vec![0, 0]).is_none()).to(be_true());
+        }
+
+        it "should not restore parents out of range" {
+            expect!(CompactRankedQuickUnion::from_parts(vec![2, 1], vec![0, 1]).is_none()).to(be_true());
+        }
+    }
+
+    describe! client_tests {
+
+        it "should read size and pairs" {
+            let input = "10\n4 3\n3 8\n\n6 5\n";
+
+            expect!(read_pairs(input.as_bytes()).ok()).to(be_equal_to(Some((10, vec![(4, 3), (3, 8), (6, 5)]))));
+        }
+
+        it "should reject pairs out of range" {
+            expect!(read_pairs("3\n1 3\n".as_bytes())).to(be_err());
+        }
+
+        it "should reject trailing data after a pair" {
+            expect!(read_pairs("3\n1 2 0\n".as_bytes())).to(be_err());
+        }
+
+        it "should reject missing q" {
+            expect!(read_pairs("3\n1\n".as_bytes())).to(be_err());
+        }
+
+        it "should stream pairs and stop at the first bad line" {
+            let (size, pairs) = pairs("3\n0 1\n\n1 x\n1 2\n".as_bytes()).unwrap();
+            let mut union_find = WeightedQuickUnion::new(size);
+            let mut out = Vec::new();
+
+            expect!(connect_pairs(&mut union_find, size, pairs, &mut out)).to(be_err());
+            expect!(String::from_utf8(out).unwrap()).to(be_equal_to("0 1\n".to_owned()));
+        }
+
+        it "should print pairs that were not connected and count components" {
+            let pairs = vec![(4, 3), (3, 8), (6, 5), (9, 4), (2, 1), (8, 9), (5, 0), (7, 2), (6, 1), (1, 0), (6, 7)];
+            let mut union_find = WeightedQuickUnion::new(10);
+            let mut out = Vec::new();
+
+            let components = connect_pairs(&mut union_find, 10, pairs.into_iter().map(Ok), &mut out);
+            expect!(components.ok()).to(be_equal_to(Some(2)));
+            expect!(String::from_utf8(out).unwrap())
+                .to(be_equal_to("4 3\n3 8\n6 5\n9 4\n2 1\n5 0\n7 2\n6 1\n".to_owned()));
+        }
     }
 }